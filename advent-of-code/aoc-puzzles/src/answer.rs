@@ -0,0 +1,165 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// The answer to one part of a puzzle.
+///
+/// Integers compare equal across the signed and unsigned variants as long as
+/// they hold the same value, so a stored `5` matches a solver's `5usize`.
+#[derive(Debug, Clone, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+    /// Multi-line ASCII art, such as letters drawn on a CRT, one row per entry
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Builds a grid answer from ASCII art rows.
+    pub fn grid<S: Into<String>>(rows: impl IntoIterator<Item = S>) -> Self {
+        Self::Grid(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// The answer as a signed integer, if it is numeric and fits.
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            Self::Unsigned(value) => i64::try_from(value).ok(),
+            Self::Signed(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The answer as an unsigned integer, if it is numeric and non-negative.
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            Self::Unsigned(value) => Some(value),
+            Self::Signed(value) => u64::try_from(value).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Unsigned(left), Self::Unsigned(right)) => left == right,
+            (Self::Signed(left), Self::Signed(right)) => left == right,
+            (Self::Unsigned(_), Self::Signed(_)) | (Self::Signed(_), Self::Unsigned(_)) => {
+                self.as_i64().is_some() && self.as_i64() == other.as_i64()
+            }
+            (Self::Text(left), Self::Text(right)) => left == right,
+            (Self::Grid(left), Self::Grid(right)) => left == right,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unsigned(value) => write!(f, "{value}"),
+            Self::Signed(value) => write!(f, "{value}"),
+            Self::Text(value) => write!(f, "{value}"),
+            Self::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($int:ty),*) => {
+        $(impl From<$int> for Answer {
+            fn from(value: $int) -> Self {
+                Self::Unsigned(value as u64)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($int:ty),*) => {
+        $(impl From<$int> for Answer {
+            fn from(value: $int) -> Self {
+                Self::Signed(value as i64)
+            }
+        })*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+impl_from_signed!(i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/// Reads an answer typed or scraped as text. Anything that isn't an integer
+/// is kept as text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Ok(match (text.parse::<u64>(), text.parse::<i64>()) {
+            (Ok(value), _) => Self::Unsigned(value),
+            (_, Ok(value)) => Self::Signed(value),
+            _ => Self::Text(text.to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_equality() {
+        assert_eq!(Answer::from(5u32), Answer::from(5i32));
+        assert_ne!(Answer::from(5u32), Answer::from(-5i32));
+        assert_ne!(Answer::from(u64::MAX), Answer::from(-1i64));
+        assert_ne!(Answer::from(5u32), Answer::from("5"));
+        assert_eq!(Answer::grid(["#.", ".#"]), Answer::grid(["#.", ".#"]));
+    }
+
+    #[test]
+    fn test_answer_from_str() {
+        let big = "18446744073709551615";
+        assert_eq!(big.parse::<Answer>(), Ok(Answer::Unsigned(u64::MAX)));
+        assert_eq!("-12".parse::<Answer>(), Ok(Answer::Signed(-12)));
+        assert_eq!("CMZ".parse::<Answer>(), Ok(Answer::from("CMZ")));
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(-12i32).to_string(), "-12");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::grid(["#.", ".#"]).to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn test_answer_serialization() {
+        assert_eq!(serde_json::to_string(&Answer::from(12u8)).unwrap(), "12");
+        assert_eq!(
+            serde_json::to_string(&Answer::from("CMZ")).unwrap(),
+            "\"CMZ\""
+        );
+        assert_eq!(
+            serde_json::from_str::<Answer>("-3").unwrap(),
+            Answer::Signed(-3)
+        );
+        assert_eq!(
+            serde_json::from_str::<Answer>("[\"#.\", \".#\"]").unwrap(),
+            Answer::grid(["#.", ".#"])
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Environment variable pointing at the data directory.
+pub const DATA_DIR_ENV_VAR: &str = "AOC_DATA_DIR";
+
+/// The data directory checked into this crate, used when nothing else is configured.
+pub const MANIFEST_DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+const CONFIG_FILE_NAME: &str = "config.toml";
+
+/// The directory holding the tooling's config files: `$XDG_CONFIG_HOME/aoc`,
+/// falling back to `~/.config/aoc`.
+pub fn config_dir() -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_home.join("aoc"))
+}
+
+/// Each year's data lives in its own directory, e.g. `data/2022/day_1/`.
+pub fn day_data_dir(data_root: &Path, year: u16, day: u8) -> PathBuf {
+    data_root.join(year.to_string()).join(format!("day_{day}"))
+}
+
+/// Where a candidate data directory came from, in the order they're searched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataRootSource {
+    Flag,
+    EnvVar,
+    ConfigFile(PathBuf),
+    Manifest,
+}
+
+impl fmt::Display for DataRootSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Flag => write!(f, "--data-dir"),
+            Self::EnvVar => write!(f, "${DATA_DIR_ENV_VAR}"),
+            Self::ConfigFile(path) => write!(f, "data_dir in {}", path.display()),
+            Self::Manifest => write!(f, "the aoc-puzzles crate"),
+        }
+    }
+}
+
+/// A location that was searched for the data directory and why it was passed over.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchedLocation {
+    pub source: DataRootSource,
+    pub outcome: String,
+}
+
+#[derive(Debug, Error)]
+#[error("Couldn't find the puzzle data directory. Searched:{}", render_searched(.searched))]
+pub struct DataRootError {
+    pub searched: Vec<SearchedLocation>,
+}
+
+fn render_searched(searched: &[SearchedLocation]) -> String {
+    searched
+        .iter()
+        .map(|location| format!("\n  {}: {}", location.source, location.outcome))
+        .collect()
+}
+
+#[derive(Debug, Deserialize)]
+struct Config {
+    data_dir: Option<PathBuf>,
+}
+
+/// Reads `data_dir` from a config file. Relative paths are relative to the
+/// file itself. A missing file isn't an error, since the file is optional.
+fn data_dir_from_config(path: &Path) -> Result<Option<PathBuf>, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(format!("couldn't be read: {err}")),
+    };
+    let config: Config =
+        toml::from_str(&contents).map_err(|err| format!("couldn't be parsed: {err}"))?;
+    let base = path.parent().unwrap_or(Path::new(""));
+    Ok(config.data_dir.map(|data_dir| base.join(data_dir)))
+}
+
+/// The places the data directory may be configured, from highest to lowest priority.
+pub struct DataRootSearch {
+    pub flag: Option<PathBuf>,
+    pub env_var: Option<PathBuf>,
+    pub config_file: Option<PathBuf>,
+    pub manifest: PathBuf,
+}
+
+impl DataRootSearch {
+    /// Searches the `--data-dir` flag, then `AOC_DATA_DIR`, then the config
+    /// file, then the data directory checked into this crate.
+    pub fn new(flag: Option<PathBuf>) -> Self {
+        Self {
+            flag,
+            env_var: env::var_os(DATA_DIR_ENV_VAR)
+                .filter(|value| !value.is_empty())
+                .map(PathBuf::from),
+            config_file: config_dir().map(|dir| dir.join(CONFIG_FILE_NAME)),
+            manifest: PathBuf::from(MANIFEST_DATA_DIR),
+        }
+    }
+
+    /// The first location that's configured. A configured location that isn't
+    /// a directory is an error rather than being skipped, so a typo doesn't
+    /// silently fall back to another directory.
+    pub fn resolve(&self) -> Result<PathBuf, DataRootError> {
+        let mut candidates = vec![
+            (DataRootSource::Flag, Ok(self.flag.clone())),
+            (DataRootSource::EnvVar, Ok(self.env_var.clone())),
+        ];
+        if let Some(config_file) = &self.config_file {
+            candidates.push((
+                DataRootSource::ConfigFile(config_file.clone()),
+                data_dir_from_config(config_file),
+            ));
+        }
+        candidates.push((DataRootSource::Manifest, Ok(Some(self.manifest.clone()))));
+
+        let mut searched = vec![];
+        for (source, path) in candidates {
+            let outcome = match path {
+                Ok(None) => {
+                    searched.push(SearchedLocation {
+                        source,
+                        outcome: "not set".to_string(),
+                    });
+                    continue;
+                }
+                Ok(Some(path)) if path.is_dir() => return Ok(path),
+                Ok(Some(path)) => format!("{} is not a directory", path.display()),
+                Err(outcome) => outcome,
+            };
+            searched.push(SearchedLocation { source, outcome });
+            break;
+        }
+        Err(DataRootError { searched })
+    }
+}
+
+/// Finds the data directory, preferring `flag` when it's given.
+pub fn resolve_data_root(flag: Option<PathBuf>) -> Result<PathBuf, DataRootError> {
+    DataRootSearch::new(flag).resolve()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search() -> DataRootSearch {
+        DataRootSearch {
+            flag: None,
+            env_var: None,
+            config_file: None,
+            manifest: PathBuf::from(MANIFEST_DATA_DIR),
+        }
+    }
+
+    #[test]
+    fn test_resolve_precedence() {
+        let root = tempfile::tempdir().unwrap();
+        let (flag, env_var) = (root.path().join("flag"), root.path().join("env"));
+        fs::create_dir_all(&flag).unwrap();
+        fs::create_dir_all(&env_var).unwrap();
+
+        assert_eq!(search().resolve().unwrap(), Path::new(MANIFEST_DATA_DIR));
+        let with_env = DataRootSearch {
+            env_var: Some(env_var.clone()),
+            ..search()
+        };
+        assert_eq!(with_env.resolve().unwrap(), env_var);
+        let with_flag = DataRootSearch {
+            flag: Some(flag.clone()),
+            ..with_env
+        };
+        assert_eq!(with_flag.resolve().unwrap(), flag);
+    }
+
+    #[test]
+    fn test_resolve_config_file() {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir_all(root.path().join("puzzles")).unwrap();
+        let config_file = root.path().join("config.toml");
+        fs::write(&config_file, "data_dir = \"puzzles\"\n").unwrap();
+        let with_config = DataRootSearch {
+            config_file: Some(config_file.clone()),
+            ..search()
+        };
+        assert_eq!(with_config.resolve().unwrap(), root.path().join("puzzles"));
+
+        fs::write(&config_file, "data_dir = 5\n").unwrap();
+        let err = with_config.resolve().unwrap_err();
+        assert!(err.searched[2].outcome.starts_with("couldn't be parsed"));
+
+        // A config file without data_dir falls through to the manifest
+        fs::write(&config_file, "").unwrap();
+        assert_eq!(with_config.resolve().unwrap(), Path::new(MANIFEST_DATA_DIR));
+    }
+
+    #[test]
+    fn test_resolve_lists_searched_locations() {
+        let missing = env::temp_dir().join("aoc-puzzles-data-does-not-exist");
+        let err = DataRootSearch {
+            env_var: Some(missing.clone()),
+            config_file: Some(PathBuf::from("/nonexistent/aoc/config.toml")),
+            ..search()
+        }
+        .resolve()
+        .unwrap_err();
+        assert_eq!(
+            err.searched,
+            vec![
+                SearchedLocation {
+                    source: DataRootSource::Flag,
+                    outcome: "not set".to_string(),
+                },
+                SearchedLocation {
+                    source: DataRootSource::EnvVar,
+                    outcome: format!("{} is not a directory", missing.display()),
+                },
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Couldn't find the puzzle data directory. Searched:\n  --data-dir: not set\n  $AOC_DATA_DIR: {} is not a directory",
+                missing.display()
+            )
+        );
+    }
+}
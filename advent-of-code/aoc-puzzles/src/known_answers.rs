@@ -0,0 +1,118 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{data, Answer, Part};
+
+pub const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+#[derive(Debug, Error)]
+pub enum AnswersError {
+    #[error("Failed to access the answers file: {0}")]
+    Io(#[from] io::Error),
+    #[error("Failed to parse the answers file: {0}")]
+    Parse(#[from] toml::de::Error),
+    #[error("Failed to serialize answers: {0}")]
+    Serialize(#[from] toml::ser::Error),
+}
+
+/// Whether a solved answer agrees with the confirmed answer on record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    Unverified,
+    Correct,
+    Incorrect { expected: Answer },
+}
+
+/// Confirmed answers for a single day, stored next to that day's input file.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub one: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub two: Option<Answer>,
+}
+
+impl KnownAnswers {
+    pub fn path(data_root: &Path, year: u16, day: u8) -> PathBuf {
+        data::day_data_dir(data_root, year, day).join(ANSWERS_FILE_NAME)
+    }
+
+    /// Loads the answers for a day. A missing file means no answers are known yet.
+    pub fn load(data_root: &Path, year: u16, day: u8) -> Result<Self, AnswersError> {
+        match fs::read_to_string(Self::path(data_root, year, day)) {
+            Ok(contents) => Ok(toml::from_str(&contents)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn save(&self, data_root: &Path, year: u16, day: u8) -> Result<(), AnswersError> {
+        fs::write(Self::path(data_root, year, day), toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.one.as_ref(),
+            Part::Two => self.two.as_ref(),
+        }
+    }
+
+    /// Records an answer, returning the previously stored one if it differed.
+    pub fn set(&mut self, part: Part, answer: &Answer) -> Option<Answer> {
+        let slot = match part {
+            Part::One => &mut self.one,
+            Part::Two => &mut self.two,
+        };
+        match slot.replace(answer.clone()) {
+            Some(previous) if previous != *answer => Some(previous),
+            _ => None,
+        }
+    }
+
+    pub fn verify(&self, part: Part, answer: &Answer) -> Verification {
+        match self.get(part) {
+            None => Verification::Unverified,
+            Some(expected) if expected == answer => Verification::Correct,
+            Some(expected) => Verification::Incorrect {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_answers_round_trip() {
+        let answers: KnownAnswers = toml::from_str("one = 444\ntwo = \"CMZ\"").unwrap();
+        assert_eq!(answers.get(Part::One), Some(&Answer::from(444)));
+        assert_eq!(answers.get(Part::Two), Some(&Answer::from("CMZ")));
+        assert_eq!(
+            toml::to_string(&answers).unwrap().trim(),
+            "one = 444\ntwo = \"CMZ\""
+        );
+    }
+
+    #[test]
+    fn test_known_answers_verify() {
+        let (one, two) = (Answer::from(1u32), Answer::from(2u32));
+        let mut answers = KnownAnswers::default();
+        assert_eq!(answers.verify(Part::One, &one), Verification::Unverified);
+        assert_eq!(answers.set(Part::One, &one), None);
+        assert_eq!(answers.verify(Part::One, &one), Verification::Correct);
+        assert_eq!(
+            answers.verify(Part::One, &two),
+            Verification::Incorrect {
+                expected: one.clone()
+            }
+        );
+        assert_eq!(answers.set(Part::One, &two), Some(one));
+    }
+}
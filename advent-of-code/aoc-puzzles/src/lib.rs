@@ -1,23 +1,47 @@
+use std::io;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
-pub mod day_1;
-pub mod day_2;
-pub mod day_3;
-pub mod day_4;
-pub mod day_5;
-pub mod day_6;
-pub mod day_7;
-pub mod day_8;
-pub mod day_9;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+mod answer;
+pub mod data;
+pub mod known_answers;
+mod observe;
+mod parsing;
+pub mod registry;
+pub mod year_2021;
+pub mod year_2022;
+
+pub use answer::Answer;
+pub use observe::{Frame, Granularity, Observer};
+pub use parsing::{InputLine, Token};
+
+// Lets `register_solver!` be used from crates that don't depend on inventory
+#[doc(hidden)]
+pub use inventory;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Part {
     One,
     Two,
 }
 
-#[derive(Debug, Error)]
+/// Errors can be serialized so that the runner can pass them between
+/// processes.
+#[derive(Debug, Error, Serialize, Deserialize)]
 pub enum PuzzleError {
+    #[error("An IO error occurred while attempting to read the input data file: {0}")]
+    DataFileError(
+        #[from]
+        #[serde(with = "io_error")]
+        io::Error,
+    ),
+    /// A day's input or example file hasn't been saved yet.
+    #[error("Day {day} has no input at {}. Download it with aoc-codegen", .path.display())]
+    MissingInput { day: u8, path: PathBuf },
     #[error("Data consistency error found in day {day}, part {part:?}. Expected {expected:?}, found {found:?}")]
     DataConsistencyError {
         day: u8,
@@ -27,18 +51,183 @@ pub enum PuzzleError {
     },
     #[error("No solution was found")]
     NoSolutionFound,
-    #[error("Failed to parse line: {0}")]
-    ParseError(String),
+    /// Malformed puzzle input.
+    #[error("Failed to parse day {day} input at line {line_number}, column {column}. Expected {expected}, found {found:?}")]
+    ParseError {
+        day: u8,
+        line_number: usize,
+        column: usize,
+        line: String,
+        expected: String,
+        found: String,
+    },
+    #[error("Day {day} of {year} has not been implemented")]
+    DayNotImplemented { year: u16, day: u8 },
+    /// A solver panicked rather than returning an error. Here and in the
+    /// runner's other limits, `part` is `None` when it was the parse step
+    /// shared by both parts that was stopped. Parts solved under limits
+    /// parse the input themselves, so their limits always name the part.
+    #[error("Day {day} {} panicked{}: {message}", describe_step(.part), describe_location(.location))]
+    SolverPanicked {
+        day: u8,
+        part: Option<Part>,
+        message: String,
+        location: Option<String>,
+    },
+    #[error("Day {day} {} timed out after {timeout:?}", describe_step(.part))]
+    TimedOut {
+        day: u8,
+        part: Option<Part>,
+        timeout: Duration,
+    },
+    #[error("Day {day} {} allocated more than {limit} bytes", describe_step(.part))]
+    MemoryLimitExceeded {
+        day: u8,
+        part: Option<Part>,
+        limit: usize,
+    },
+}
+
+/// Serializes an `io::Error` as its message, which is all that's kept of
+/// it when it's read back.
+mod io_error {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(err: &io::Error, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(err)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<io::Error, D::Error> {
+        String::deserialize(deserializer).map(io::Error::other)
+    }
+}
+
+fn describe_step(part: &Option<Part>) -> String {
+    match part {
+        Some(part) => format!("part {part:?}"),
+        None => "parser".to_string(),
+    }
+}
+
+fn describe_location(location: &Option<String>) -> String {
+    location
+        .as_ref()
+        .map_or(String::new(), |location| format!(" at {location}"))
+}
+
+impl PuzzleError {
+    /// A short, stable name for the variant, for tools that consume the
+    /// runner's machine readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::DataFileError(_) => "data_file",
+            Self::MissingInput { .. } => "missing_input",
+            Self::DataConsistencyError { .. } => "data_consistency",
+            Self::NoSolutionFound => "no_solution",
+            Self::ParseError { .. } => "parse",
+            Self::DayNotImplemented { .. } => "day_not_implemented",
+            Self::SolverPanicked { .. } => "solver_panicked",
+            Self::TimedOut { .. } => "timed_out",
+            Self::MemoryLimitExceeded { .. } => "memory_limit_exceeded",
+        }
+    }
+}
+
+/// A day's solution, split into a parse step shared by both parts and a
+/// solve step for each part.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError>;
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError>;
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError>;
+
+    /// Solves a part while passing `observer` frames of the solver's state
+    /// as it goes. Days that aren't simulations return `None`.
+    fn observe(
+        _input: &Self::Input,
+        _part: Part,
+        _observer: &mut dyn Observer,
+    ) -> Option<Result<Answer, PuzzleError>> {
+        None
+    }
 }
 
-pub trait Puzzle {
-    fn new() -> Box<Self>
-    where
-        Self: Sized;
+/// Puzzle input that has already been parsed by a `Solution`, ready to solve either part.
+///
+/// Both parts may be solved at once from different threads, so the input
+/// must be `Send + Sync`.
+pub trait ParsedInput: Send + Sync {
+    fn solve(&self, part: Part) -> Result<Answer, PuzzleError>;
+
+    fn observe(
+        &self,
+        part: Part,
+        observer: &mut dyn Observer,
+    ) -> Option<Result<Answer, PuzzleError>>;
+}
+
+/// Object-safe view of a day's solver, used by the registry and runner to
+/// treat every day uniformly. Solvers are shared between the runner's worker
+/// threads, so they must be `Send + Sync`.
+pub trait Puzzle: Send + Sync {
+    fn parse(
+        &self,
+        input: Box<dyn Iterator<Item = String>>,
+    ) -> Result<Box<dyn ParsedInput>, PuzzleError>;
 
     fn solve(
         &self,
         input: Box<dyn Iterator<Item = String>>,
         part: Part,
-    ) -> Result<String, PuzzleError>;
+    ) -> Result<Answer, PuzzleError> {
+        self.parse(input)?.solve(part)
+    }
+}
+
+/// Adapts a typed `Solution` to the object-safe `Puzzle` trait.
+pub struct SolutionAdapter<S>(PhantomData<fn() -> S>);
+
+impl<S> Default for SolutionAdapter<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+struct Parsed<S: Solution>(S::Input);
+
+impl<S> ParsedInput for Parsed<S>
+where
+    S: Solution,
+    S::Input: Send + Sync,
+{
+    fn solve(&self, part: Part) -> Result<Answer, PuzzleError> {
+        match part {
+            Part::One => S::part_one(&self.0),
+            Part::Two => S::part_two(&self.0),
+        }
+    }
+
+    fn observe(
+        &self,
+        part: Part,
+        observer: &mut dyn Observer,
+    ) -> Option<Result<Answer, PuzzleError>> {
+        S::observe(&self.0, part, observer)
+    }
+}
+
+impl<S> Puzzle for SolutionAdapter<S>
+where
+    S: Solution + 'static,
+    S::Input: Send + Sync + 'static,
+{
+    fn parse(
+        &self,
+        input: Box<dyn Iterator<Item = String>>,
+    ) -> Result<Box<dyn ParsedInput>, PuzzleError> {
+        Ok(Box::new(Parsed::<S>(S::parse(input)?)))
+    }
 }
@@ -0,0 +1,145 @@
+use serde::Serialize;
+
+/// How often an observed solver emits a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    /// After every step of the simulation
+    Step,
+    /// After every line of the input
+    Instruction,
+}
+
+/// A picture of a solver's state partway through a solution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Frame {
+    /// Rows of characters, top row first
+    Grid {
+        title: String,
+        rows: Vec<String>,
+    },
+    Text {
+        title: String,
+        text: String,
+    },
+    /// Named values such as counters, in the order they're shown
+    Stats {
+        title: String,
+        stats: Vec<(String, String)>,
+    },
+}
+
+impl Frame {
+    pub fn grid(title: impl Into<String>, rows: Vec<String>) -> Self {
+        Self::Grid {
+            title: title.into(),
+            rows,
+        }
+    }
+
+    pub fn text(title: impl Into<String>, text: impl Into<String>) -> Self {
+        Self::Text {
+            title: title.into(),
+            text: text.into(),
+        }
+    }
+
+    pub fn stats<N, V>(title: impl Into<String>, stats: impl IntoIterator<Item = (N, V)>) -> Self
+    where
+        N: Into<String>,
+        V: ToString,
+    {
+        Self::Stats {
+            title: title.into(),
+            stats: stats
+                .into_iter()
+                .map(|(name, value)| (name.into(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    pub fn title(&self) -> &str {
+        match self {
+            Self::Grid { title, .. } | Self::Text { title, .. } | Self::Stats { title, .. } => {
+                title
+            }
+        }
+    }
+
+    /// The frame as plain text, under a `== title ==` heading the way the
+    /// puzzle descriptions label their drawings.
+    pub fn render(&self) -> String {
+        let body = match self {
+            Self::Grid { rows, .. } => rows.join("\n"),
+            Self::Text { text, .. } => text.clone(),
+            Self::Stats { stats, .. } => {
+                let width = stats.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+                stats
+                    .iter()
+                    .map(|(name, value)| format!("{name:<width$}  {value}"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+        };
+        format!("== {} ==\n\n{body}", self.title())
+    }
+}
+
+/// Receives frames from a solver as it runs, e.g. to draw them in the
+/// terminal or save them to replay later.
+pub trait Observer {
+    /// How often the solver should emit frames. Solvers without distinct
+    /// steps may emit at the same rate for either granularity.
+    fn granularity(&self) -> Granularity;
+
+    fn frame(&mut self, frame: Frame);
+}
+
+/// Collects every frame, for tests.
+#[cfg(test)]
+pub(crate) struct FrameLog {
+    pub granularity: Granularity,
+    pub frames: Vec<Frame>,
+}
+
+#[cfg(test)]
+impl FrameLog {
+    pub fn new(granularity: Granularity) -> Self {
+        Self {
+            granularity,
+            frames: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+impl Observer for FrameLog {
+    fn granularity(&self) -> Granularity {
+        self.granularity
+    }
+
+    fn frame(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_render() {
+        let grid = Frame::grid("R 4", vec!["s##TH".to_string()]);
+        assert_eq!(grid.render(), "== R 4 ==\n\ns##TH");
+        let stats = Frame::stats("After 18 days", [("fish", 26), ("age 0", 3)]);
+        assert_eq!(stats.render(), "== After 18 days ==\n\nfish   26\nage 0  3");
+        assert_eq!(
+            serde_json::to_value(&stats).unwrap(),
+            serde_json::json!({
+                "type": "stats",
+                "title": "After 18 days",
+                "stats": [["fish", "26"], ["age 0", "3"]],
+            })
+        );
+    }
+}
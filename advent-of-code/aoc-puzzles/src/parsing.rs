@@ -0,0 +1,235 @@
+use crate::PuzzleError;
+
+/// A single line of puzzle input along with where it came from, used to
+/// build `PuzzleError::ParseError`s that point at the offending text.
+#[derive(Debug, Clone, Copy)]
+pub struct InputLine<'a> {
+    day: u8,
+    line_number: usize,
+    line: &'a str,
+}
+
+impl<'a> InputLine<'a> {
+    /// Wraps a line of input. `index` is the zero-based position of the line in the input.
+    pub fn new(day: u8, index: usize, line: &'a str) -> Self {
+        Self {
+            day,
+            line_number: index + 1,
+            line,
+        }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.line
+    }
+
+    /// The whole line as a token, to split into smaller ones.
+    pub fn token(&self) -> Token<'a> {
+        Token {
+            text: self.line,
+            offset: 0,
+        }
+    }
+
+    /// An error at a one-based column of this line.
+    pub fn error(
+        &self,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> PuzzleError {
+        PuzzleError::ParseError {
+            day: self.day,
+            line_number: self.line_number,
+            column,
+            line: self.line.to_string(),
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// An error pointing at `token`. A token that doesn't fit this line,
+    /// because it came from another one, points just past its end.
+    pub fn error_at(&self, token: Token, expected: impl Into<String>) -> PuzzleError {
+        let column = match self.line.get(..token.offset) {
+            Some(before) if token.offset + token.text.len() <= self.line.len() => {
+                before.chars().count() + 1
+            }
+            _ => self.line.chars().count() + 1,
+        };
+        self.error(column, expected, token.text)
+    }
+
+    /// An error for a line that ended before an expected piece was found.
+    pub fn error_at_end(&self, expected: impl Into<String>) -> PuzzleError {
+        self.error(self.line.chars().count() + 1, expected, "end of line")
+    }
+
+    /// Splits the line on `delim`, erroring at the end of the line if it isn't present.
+    pub fn split_once(
+        &self,
+        delim: &str,
+        expected: &str,
+    ) -> Result<(Token<'a>, Token<'a>), PuzzleError> {
+        self.token()
+            .split_once(delim)
+            .ok_or_else(|| self.error_at_end(expected))
+    }
+
+    /// Parses `token`, reporting its column on failure.
+    pub fn parse<T: std::str::FromStr>(
+        &self,
+        token: Token,
+        expected: &str,
+    ) -> Result<T, PuzzleError> {
+        token
+            .text
+            .parse()
+            .map_err(|_| self.error_at(token, expected))
+    }
+}
+
+/// A piece of an `InputLine` that remembers the byte offset it starts at, so
+/// that errors can point at it. Tokens are only made by splitting a line, so
+/// the offset always falls between characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Token<'a> {
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The part of this token from byte `start` to byte `end`.
+    fn slice(&self, start: usize, end: usize) -> Self {
+        Self {
+            text: &self.text[start..end],
+            offset: self.offset + start,
+        }
+    }
+
+    /// Splits the token at every `delim`, like `str::split`.
+    pub fn split(self, delim: &str) -> impl Iterator<Item = Token<'a>> {
+        let mut pieces = vec![];
+        let mut start = 0;
+        for (index, _) in self.text.match_indices(delim) {
+            pieces.push(self.slice(start, index));
+            start = index + delim.len();
+        }
+        pieces.push(self.slice(start, self.text.len()));
+        pieces.into_iter()
+    }
+
+    /// Splits the token on runs of whitespace, like `str::split_whitespace`.
+    pub fn split_whitespace(self) -> impl Iterator<Item = Token<'a>> {
+        let mut pieces = vec![];
+        let mut start = None;
+        for (index, char) in self.text.char_indices() {
+            match (start, char.is_whitespace()) {
+                (None, false) => start = Some(index),
+                (Some(piece_start), true) => {
+                    pieces.push(self.slice(piece_start, index));
+                    start = None;
+                }
+                _ => (),
+            }
+        }
+        if let Some(piece_start) = start {
+            pieces.push(self.slice(piece_start, self.text.len()));
+        }
+        pieces.into_iter()
+    }
+
+    /// Splits the token at the first `delim`, like `str::split_once`.
+    pub fn split_once(self, delim: &str) -> Option<(Token<'a>, Token<'a>)> {
+        let index = self.text.find(delim)?;
+        Some((
+            self.slice(0, index),
+            self.slice(index + delim.len(), self.text.len()),
+        ))
+    }
+
+    /// The token without a leading `prefix`, if it has one.
+    pub fn strip_prefix(self, prefix: &str) -> Option<Token<'a>> {
+        self.text
+            .starts_with(prefix)
+            .then(|| self.slice(prefix.len(), self.text.len()))
+    }
+
+    /// The token without leading and trailing whitespace.
+    pub fn trim(self) -> Token<'a> {
+        let trimmed_start = self.text.trim_start();
+        let start = self.text.len() - trimmed_start.len();
+        self.slice(start, start + trimmed_start.trim_end().len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_at_points_to_token() {
+        let text = "move 3 from x to 2";
+        let line = InputLine::new(5, 1, text);
+        let token = line.token().split(" ").nth(3).unwrap();
+        match line.parse::<usize>(token, "a stack number") {
+            Err(PuzzleError::ParseError {
+                day,
+                line_number,
+                column,
+                line,
+                expected,
+                found,
+                ..
+            }) => {
+                assert_eq!(day, 5);
+                assert_eq!(line_number, 2);
+                assert_eq!(column, 13);
+                assert_eq!(line, text);
+                assert_eq!(expected, "a stack number");
+                assert_eq!(found, "x");
+            }
+            _ => panic!("Expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_error_at_end() {
+        let line = InputLine::new(9, 0, "R");
+        match line.split_once(" ", "a step count") {
+            Err(PuzzleError::ParseError { column, found, .. }) => {
+                assert_eq!(column, 2);
+                assert_eq!(found, "end of line");
+            }
+            _ => panic!("Expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_tokens_keep_their_columns() {
+        let text = "é 12,  x -> y ";
+        let line = InputLine::new(5, 0, text);
+        let pieces: Vec<&str> = line.token().split_whitespace().map(|t| t.text()).collect();
+        assert_eq!(pieces, ["é", "12,", "x", "->", "y"]);
+        let (left, right) = line.token().split_once(" -> ").unwrap();
+        assert_eq!(right.trim().text(), "y");
+        let column = |token| match line.error_at(token, "anything") {
+            PuzzleError::ParseError { column, .. } => column,
+            _ => unreachable!(),
+        };
+        assert_eq!(column(right.trim()), 13);
+        assert_eq!(column(left.split(",").nth(1).unwrap().trim()), 8);
+        let twelve = left.strip_prefix("é ").unwrap().split(",").next().unwrap();
+        assert_eq!(column(twelve), 3);
+
+        // A token from another line that starts partway through this line's
+        // first character can't make the error panic
+        let other = InputLine::new(5, 1, "xyz");
+        let stray = other.token().strip_prefix("x").unwrap();
+        assert_eq!(column(stray), text.chars().count() + 1);
+    }
+}
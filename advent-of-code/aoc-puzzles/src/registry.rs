@@ -0,0 +1,119 @@
+use std::collections::BTreeSet;
+
+use crate::{Puzzle, PuzzleError};
+
+/// A solver entry submitted by a day module via `register_solver!`.
+pub struct SolverRegistration {
+    year: u16,
+    day: u8,
+    constructor: fn() -> Box<dyn Puzzle>,
+}
+
+impl SolverRegistration {
+    pub const fn new(year: u16, day: u8, constructor: fn() -> Box<dyn Puzzle>) -> Self {
+        Self {
+            year,
+            day,
+            constructor,
+        }
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn solver(&self) -> Box<dyn Puzzle> {
+        (self.constructor)()
+    }
+}
+
+inventory::collect!(SolverRegistration);
+
+/// Registers a day's `Solver` with the registry so the runner can find it.
+///
+/// Every `day_N` module should invoke this once, e.g. `register_solver!(2022, 1, Solver);`.
+#[macro_export]
+macro_rules! register_solver {
+    ($year:expr, $day:expr, $solver:ty) => {
+        $crate::inventory::submit! {
+            $crate::registry::SolverRegistration::new($year, $day, || -> Box<dyn $crate::Puzzle> {
+                Box::new($crate::SolutionAdapter::<$solver>::default())
+            })
+        }
+    };
+}
+
+pub fn registrations() -> impl Iterator<Item = &'static SolverRegistration> {
+    inventory::iter::<SolverRegistration>.into_iter()
+}
+
+/// Looks up the solver for the given year and day.
+pub fn get_solver(year: u16, day: u8) -> Result<Box<dyn Puzzle>, PuzzleError> {
+    registrations()
+        .find(|registration| registration.year == year && registration.day == day)
+        .map(SolverRegistration::solver)
+        .ok_or(PuzzleError::DayNotImplemented { year, day })
+}
+
+/// All years with at least one registered solver, in ascending order.
+pub fn available_years() -> Vec<u16> {
+    let years: BTreeSet<u16> = registrations().map(SolverRegistration::year).collect();
+    years.into_iter().collect()
+}
+
+/// All days with a registered solver for the given year, in ascending order.
+pub fn available_days(year: u16) -> Vec<u8> {
+    let days: BTreeSet<u8> = registrations()
+        .filter(|registration| registration.year == year)
+        .map(SolverRegistration::day)
+        .collect();
+    days.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_available_days() {
+        assert_eq!(available_days(2022), (1..=9).collect::<Vec<u8>>());
+        assert_eq!(available_days(2021), vec![1, 2, 3, 4, 5, 6, 7, 8, 10]);
+        assert!(available_days(1999).is_empty());
+    }
+
+    #[test]
+    fn test_available_years() {
+        assert_eq!(available_years(), vec![2021, 2022]);
+    }
+
+    #[test]
+    fn test_registrations_are_unique() {
+        let mut seen = HashSet::new();
+        for registration in registrations() {
+            assert!(
+                seen.insert((registration.year(), registration.day())),
+                "Day {} of {} was registered more than once",
+                registration.day(),
+                registration.year()
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_solver_not_implemented() {
+        let res = get_solver(2022, 26);
+        assert!(matches!(
+            res,
+            Err(PuzzleError::DayNotImplemented {
+                year: 2022,
+                day: 26
+            })
+        ));
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::VecDeque;
+
+use crate::{register_solver, Answer, InputLine, PuzzleError, Solution};
+
+const DAY: u8 = 1;
+
+pub struct Solver;
+
+register_solver!(2021, 1, Solver);
+
+impl Solver {
+    fn parse_input(input: Box<dyn Iterator<Item = String>>) -> Result<Vec<i32>, PuzzleError> {
+        input
+            .enumerate()
+            .map(|(index, depth)| {
+                let line = InputLine::new(DAY, index, &depth);
+                line.parse(line.token().trim(), "a depth")
+            })
+            .collect()
+    }
+
+    fn puzzle_one(depths: &[i32]) -> Result<Answer, PuzzleError> {
+        let depth_increases = depths.windows(2).filter(|pair| pair[1] > pair[0]).count();
+        Ok(depth_increases.into())
+    }
+
+    fn puzzle_two(depths: &[i32]) -> Result<Answer, PuzzleError> {
+        let mut depth_iter = depths.iter();
+        let mut sliding_window = SlidingWindow::new(3);
+        for &depth in depth_iter.by_ref().take(3) {
+            sliding_window.add_element(depth);
+        }
+
+        let mut prior_depth = sliding_window.current_sum();
+        let mut depth_increases = 0;
+        for &depth in depth_iter {
+            sliding_window.add_element(depth);
+            let current_depth = sliding_window.current_sum();
+            if current_depth > prior_depth {
+                depth_increases += 1;
+            }
+            prior_depth = current_depth;
+        }
+        Ok(depth_increases.into())
+    }
+}
+
+impl Solution for Solver {
+    type Input = Vec<i32>;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        Solver::parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_two(input)
+    }
+}
+
+struct SlidingWindow {
+    current_sum: i32,
+    data: VecDeque<i32>,
+}
+
+impl SlidingWindow {
+    pub fn new(window_size: usize) -> Self {
+        Self {
+            current_sum: 0,
+            data: VecDeque::with_capacity(window_size),
+        }
+    }
+
+    pub fn current_sum(&self) -> i32 {
+        self.current_sum
+    }
+
+    pub fn add_element(&mut self, element: i32) -> Option<i32> {
+        if self.data.len() < self.data.capacity() {
+            self.data.push_back(element);
+            self.current_sum += element;
+            None
+        } else {
+            let evicted_element = self.data.pop_front().unwrap();
+            self.data.push_back(element);
+            self.current_sum += element - evicted_element;
+            Some(evicted_element)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    fn get_test_input() -> Box<dyn Iterator<Item = String>> {
+        let depths = [
+            "199", "200", "208", "210", "200", "207", "240", "269", "260", "263",
+        ];
+        Box::new(depths.into_iter().map(String::from))
+    }
+
+    #[test]
+    fn test_puzzle_one() {
+        let depths = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(Solver::puzzle_one(&depths).unwrap(), Answer::from(7));
+    }
+
+    #[test]
+    fn test_puzzle_two() {
+        let depths = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(Solver::puzzle_two(&depths).unwrap(), Answer::from(5));
+    }
+
+    #[test]
+    fn test_registered_solver() {
+        let solver = crate::registry::get_solver(2021, 1).unwrap();
+        assert_eq!(
+            solver.solve(get_test_input(), Part::One).unwrap(),
+            Answer::from(7)
+        );
+        assert_eq!(
+            solver.solve(get_test_input(), Part::Two).unwrap(),
+            Answer::from(5)
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{register_solver, Answer, InputLine, PuzzleError, Solution};
+
+const DAY: u8 = 10;
+
+/// The character that closes a chunk opened by `opener`.
+fn closer_for(opener: char) -> Option<char> {
+    match opener {
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '{' => Some('}'),
+        '<' => Some('>'),
+        _ => None,
+    }
+}
+
+fn syntax_error_score(closer: char) -> u64 {
+    match closer {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => 0,
+    }
+}
+
+fn completion_score(closer: char) -> u64 {
+    match closer {
+        ')' => 1,
+        ']' => 2,
+        '}' => 3,
+        '>' => 4,
+        _ => 0,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum LineStatus {
+    /// The first closing character that doesn't match the open chunk
+    Corrupted(char),
+    /// The closing characters needed to finish every open chunk, innermost first
+    Incomplete(Vec<char>),
+}
+
+fn check_line(line: &str) -> LineStatus {
+    let mut expected_closers = vec![];
+    for char in line.chars() {
+        if let Some(closer) = closer_for(char) {
+            expected_closers.push(closer);
+        } else if expected_closers.pop() != Some(char) {
+            return LineStatus::Corrupted(char);
+        }
+    }
+    expected_closers.reverse();
+    LineStatus::Incomplete(expected_closers)
+}
+
+pub struct Solver;
+
+register_solver!(2021, 10, Solver);
+
+impl Solver {
+    fn parse_input(input: Box<dyn Iterator<Item = String>>) -> Result<Vec<String>, PuzzleError> {
+        let mut nav_system_lines = vec![];
+        for (index, nav_line) in input.enumerate() {
+            let line = InputLine::new(DAY, index, &nav_line);
+            if let Some((column, char)) = nav_line
+                .chars()
+                .enumerate()
+                .find(|&(_, char)| closer_for(char).is_none() && syntax_error_score(char) == 0)
+            {
+                return Err(line.error(column + 1, "one of ()[]{}<>", char.to_string()));
+            }
+            nav_system_lines.push(nav_line);
+        }
+        Ok(nav_system_lines)
+    }
+
+    fn puzzle_one(nav_system_lines: &[String]) -> Result<Answer, PuzzleError> {
+        let score: u64 = nav_system_lines
+            .iter()
+            .filter_map(|line| match check_line(line) {
+                LineStatus::Corrupted(char) => Some(syntax_error_score(char)),
+                LineStatus::Incomplete(_) => None,
+            })
+            .sum();
+        Ok(score.into())
+    }
+
+    fn puzzle_two(nav_system_lines: &[String]) -> Result<Answer, PuzzleError> {
+        let mut scores: Vec<u64> = nav_system_lines
+            .iter()
+            .filter_map(|line| match check_line(line) {
+                LineStatus::Incomplete(closers) if !closers.is_empty() => Some(
+                    closers
+                        .into_iter()
+                        .fold(0, |score, closer| score * 5 + completion_score(closer)),
+                ),
+                _ => None,
+            })
+            .collect();
+        if scores.is_empty() {
+            return Err(PuzzleError::NoSolutionFound);
+        }
+        scores.sort_unstable();
+        Ok(scores[scores.len() / 2].into())
+    }
+}
+
+impl Solution for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        Solver::parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_input() -> Box<dyn Iterator<Item = String>> {
+        let nav_system_lines = [
+            "[({(<(())[]>[[{[]{<()<>>",
+            "[(()[<>])]({[<{<<[]>>(",
+            "{([(<{}[<>[]}>{[]{[(<()>",
+            "(((({<>}<{<{<>}{[]{[]{}",
+            "[[<[([]))<([[{}[[()]]]",
+            "[{[{({}]{}}([{[{{{}}([]",
+            "{<[[]]>}<{[{[{[]{()[[[]",
+            "[<(<(<(<{}))><([]([]()",
+            "<{([([[(<>()){}]>(<<{{",
+            "<{([{{}}[<[[[<>{}]]]>[]]",
+        ];
+        Box::new(nav_system_lines.into_iter().map(String::from))
+    }
+
+    #[test]
+    fn test_check_line() {
+        assert_eq!(
+            check_line("{([(<{}[<>[]}>{[]{[(<()>"),
+            LineStatus::Corrupted('}')
+        );
+        assert_eq!(
+            check_line("[({(<(())[]>[[{[]{<()<>>"),
+            LineStatus::Incomplete("}}]])})]".chars().collect())
+        );
+    }
+
+    #[test]
+    fn test_puzzle_one() {
+        let nav_system_lines = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(
+            Solver::puzzle_one(&nav_system_lines).unwrap(),
+            Answer::from(26397)
+        );
+    }
+
+    #[test]
+    fn test_puzzle_two() {
+        let nav_system_lines = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(
+            Solver::puzzle_two(&nav_system_lines).unwrap(),
+            Answer::from(288957)
+        );
+    }
+
+    #[test]
+    fn test_parse_input_malformed() {
+        let input = Box::new(["[({(", "[(x"].into_iter().map(String::from));
+        assert!(matches!(
+            Solver::parse_input(input),
+            Err(PuzzleError::ParseError {
+                line_number: 2,
+                column: 3,
+                ..
+            })
+        ));
+    }
+}
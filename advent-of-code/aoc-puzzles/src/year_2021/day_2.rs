@@ -0,0 +1,135 @@
+use crate::{register_solver, Answer, InputLine, PuzzleError, Solution, Token};
+
+const DAY: u8 = 2;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Forward,
+    Up,
+    Down,
+}
+
+impl Direction {
+    /// Parses `token`, a piece of `line`, as one of forward, up or down.
+    fn parse(line: &InputLine, token: Token) -> Result<Self, PuzzleError> {
+        let direction = match token.text() {
+            "forward" => Self::Forward,
+            "up" => Self::Up,
+            "down" => Self::Down,
+            _ => return Err(line.error_at(token, "one of forward, up or down")),
+        };
+        Ok(direction)
+    }
+}
+
+pub struct Solver;
+
+register_solver!(2021, 2, Solver);
+
+impl Solver {
+    fn parse_input(
+        input: Box<dyn Iterator<Item = String>>,
+    ) -> Result<Vec<(Direction, i32)>, PuzzleError> {
+        let mut directions = vec![];
+        for (index, direction_input) in input.enumerate() {
+            let line = InputLine::new(DAY, index, &direction_input);
+            let (direction, distance) = line.split_once(" ", "a direction and distance")?;
+            let direction = Direction::parse(&line, direction)?;
+            let distance = line.parse(distance, "a distance")?;
+            directions.push((direction, distance));
+        }
+        Ok(directions)
+    }
+
+    fn puzzle_one(directions: &[(Direction, i32)]) -> Result<Answer, PuzzleError> {
+        let mut horizontal_position = 0;
+        let mut vertical_position = 0;
+        for &(direction, distance) in directions {
+            match direction {
+                Direction::Forward => horizontal_position += distance,
+                // Distance down and up measured from surface, so going down
+                // represents getting deeper, or increasing distance.
+                Direction::Up => vertical_position -= distance,
+                Direction::Down => vertical_position += distance,
+            }
+        }
+        Ok((horizontal_position * vertical_position).into())
+    }
+
+    fn puzzle_two(directions: &[(Direction, i32)]) -> Result<Answer, PuzzleError> {
+        let mut horizontal_position = 0;
+        let mut vertical_position = 0;
+        let mut aim = 0;
+        for &(direction, distance) in directions {
+            match direction {
+                Direction::Forward => {
+                    horizontal_position += distance;
+                    vertical_position += aim * distance;
+                }
+                // Distance down and up measured from surface, so going down
+                // represents getting deeper, or increasing aim.
+                Direction::Up => aim -= distance,
+                Direction::Down => aim += distance,
+            }
+        }
+        Ok((horizontal_position * vertical_position).into())
+    }
+}
+
+impl Solution for Solver {
+    type Input = Vec<(Direction, i32)>;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        Solver::parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_input() -> Box<dyn Iterator<Item = String>> {
+        let directions = [
+            "forward 5",
+            "down 5",
+            "forward 8",
+            "up 3",
+            "down 8",
+            "forward 2",
+        ];
+        Box::new(directions.into_iter().map(String::from))
+    }
+
+    #[test]
+    fn test_puzzle_one() {
+        let directions = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(Solver::puzzle_one(&directions).unwrap(), Answer::from(150));
+    }
+
+    #[test]
+    fn test_puzzle_two() {
+        let directions = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(Solver::puzzle_two(&directions).unwrap(), Answer::from(900));
+    }
+
+    #[test]
+    fn test_parse_input_malformed() {
+        let input = Box::new(["forward 5", "backward 2"].into_iter().map(String::from));
+        assert!(matches!(
+            Solver::parse_input(input),
+            Err(PuzzleError::ParseError {
+                line_number: 2,
+                column: 1,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{register_solver, Answer, InputLine, Part, PuzzleError, Solution};
+
+const DAY: u8 = 3;
+/// The widest reading that fits in a rating.
+const MAX_WIDTH: usize = u32::BITS as usize;
+
+pub struct Solver;
+
+register_solver!(2021, 3, Solver);
+
+/// Counts how many of `readings` have a 1 at `position`.
+fn count_ones(readings: &[&str], position: usize) -> usize {
+    readings
+        .iter()
+        .filter(|reading| reading.as_bytes()[position] == b'1')
+        .count()
+}
+
+/// Narrows the readings down one bit position at a time, keeping those with
+/// the most common bit in that position (1 on a tie), or the least common bit
+/// (0 on a tie), until a single reading is left.
+fn find_rating(
+    readings: &[String],
+    keep_most_common: bool,
+    part: Part,
+) -> Result<u32, PuzzleError> {
+    let mut candidates: Vec<&str> = readings.iter().map(String::as_str).collect();
+    let width = candidates.first().map_or(0, |reading| reading.len());
+    for position in 0..width {
+        if candidates.len() <= 1 {
+            break;
+        }
+        let ones = count_ones(&candidates, position);
+        let most_common = if ones * 2 >= candidates.len() {
+            b'1'
+        } else {
+            b'0'
+        };
+        candidates
+            .retain(|reading| (reading.as_bytes()[position] == most_common) == keep_most_common);
+    }
+
+    match candidates[..] {
+        [rating] => Ok(u32::from_str_radix(rating, 2).expect("Readings are validated when parsed")),
+        _ => Err(PuzzleError::DataConsistencyError {
+            day: DAY,
+            part,
+            expected: "a single reading to be left after filtering".to_string(),
+            found: format!("{candidates:?}"),
+        }),
+    }
+}
+
+impl Solver {
+    /// Reads the diagnostic report, checking every reading is the same
+    /// width and made up of only 1s and 0s.
+    fn parse_input(input: Box<dyn Iterator<Item = String>>) -> Result<Vec<String>, PuzzleError> {
+        let mut readings: Vec<String> = vec![];
+        for (index, reading) in input.enumerate() {
+            let line = InputLine::new(DAY, index, &reading);
+            if let Some((column, bit)) = reading
+                .chars()
+                .enumerate()
+                .find(|(_, bit)| !matches!(bit, '0' | '1'))
+            {
+                return Err(line.error(column + 1, "a bit, either 1 or 0", bit.to_string()));
+            }
+            if reading.len() > MAX_WIDTH {
+                return Err(line.error(
+                    MAX_WIDTH + 1,
+                    format!("a reading at most {MAX_WIDTH} bits wide"),
+                    &reading[MAX_WIDTH..],
+                ));
+            }
+            if let Some(first) = readings.first() {
+                if reading.len() != first.len() {
+                    return Err(line.error(
+                        1,
+                        format!("a reading {} bits wide", first.len()),
+                        reading.as_str(),
+                    ));
+                }
+            }
+            readings.push(reading);
+        }
+        Ok(readings)
+    }
+
+    fn puzzle_one(readings: &[String]) -> Result<Answer, PuzzleError> {
+        let readings: Vec<&str> = readings.iter().map(String::as_str).collect();
+        let width = readings.first().map_or(0, |reading| reading.len());
+        let bit_pivot_point = readings.len() / 2;
+
+        let mut gamma: u32 = 0;
+        let mut epsilon: u32 = 0;
+        for position in 0..width {
+            gamma <<= 1;
+            epsilon <<= 1;
+            if count_ones(&readings, position) >= bit_pivot_point {
+                gamma += 1;
+            } else {
+                epsilon += 1;
+            }
+        }
+        Ok((u64::from(gamma) * u64::from(epsilon)).into())
+    }
+
+    fn puzzle_two(readings: &[String]) -> Result<Answer, PuzzleError> {
+        let oxygen_generator_rating = find_rating(readings, true, Part::Two)?;
+        let co2_scrubber_rating = find_rating(readings, false, Part::Two)?;
+        Ok((u64::from(oxygen_generator_rating) * u64::from(co2_scrubber_rating)).into())
+    }
+}
+
+impl Solution for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        Solver::parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_input() -> Box<dyn Iterator<Item = String>> {
+        let readings = [
+            "00100", "11110", "10110", "10111", "10101", "01111", "00111", "11100", "10000",
+            "11001", "00010", "01010",
+        ];
+        Box::new(readings.into_iter().map(String::from))
+    }
+
+    #[test]
+    fn test_puzzle_one() {
+        let readings = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(Solver::puzzle_one(&readings).unwrap(), Answer::from(198));
+    }
+
+    #[test]
+    fn test_find_rating() {
+        let readings = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(find_rating(&readings, true, Part::Two).unwrap(), 23);
+        assert_eq!(find_rating(&readings, false, Part::Two).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_puzzle_two() {
+        let readings = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(Solver::puzzle_two(&readings).unwrap(), Answer::from(230));
+    }
+
+    #[test]
+    fn test_parse_input_malformed() {
+        let input = Box::new(["00100", "11210"].into_iter().map(String::from));
+        assert!(matches!(
+            Solver::parse_input(input),
+            Err(PuzzleError::ParseError {
+                line_number: 2,
+                column: 3,
+                ..
+            })
+        ));
+        let input = Box::new(["00100", "1110"].into_iter().map(String::from));
+        assert!(matches!(
+            Solver::parse_input(input),
+            Err(PuzzleError::ParseError { line_number: 2, .. })
+        ));
+
+        let wide = "1".repeat(33);
+        let input = Box::new([wide].into_iter());
+        assert!(matches!(
+            Solver::parse_input(input),
+            Err(PuzzleError::ParseError {
+                line_number: 1,
+                column: 33,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_widest_readings() {
+        let (a, b) = ("10".repeat(16), "01".repeat(16));
+        let readings = vec![a.clone(), a.clone(), a, b];
+        assert_eq!(
+            Solver::puzzle_one(&readings).unwrap(),
+            Answer::from(0xaaaa_aaaa_u64 * 0x5555_5555)
+        );
+    }
+}
@@ -0,0 +1,314 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{register_solver, Answer, InputLine, PuzzleError, Solution, Token};
+
+const BINGO_BOARD_SIZE: usize = 5;
+const DAY: u8 = 4;
+
+/// The numbers drawn, every board, and which boards each number appears on.
+#[derive(Debug)]
+pub struct Bingo {
+    numbers_called: Vec<u16>,
+    boards: Vec<BingoBoard>,
+    numbers_to_boards: HashMap<u16, Vec<usize>>,
+}
+
+#[derive(Debug, Clone)]
+struct BingoBoard {
+    numbers: HashSet<u16>,
+    number_to_lines: HashMap<u16, Vec<usize>>,
+    lines: Vec<u16>,
+    board_size: usize,
+}
+
+impl BingoBoard {
+    pub fn new(board_size: usize) -> Self {
+        Self {
+            numbers: HashSet::new(),
+            number_to_lines: HashMap::new(),
+            lines: vec![board_size as u16; board_size * 2],
+            board_size,
+        }
+    }
+
+    pub fn add_number(&mut self, number: u16, x_position: usize, y_position: usize) {
+        self.numbers.insert(number);
+        let number_to_line_vec = self.number_to_lines.entry(number).or_default();
+        // X coordinates
+        number_to_line_vec.push(x_position);
+
+        // Y coordinates. Add board size to y position
+        number_to_line_vec.push(y_position + self.board_size);
+    }
+
+    pub fn mark_number_called(&mut self, number: u16) -> bool {
+        if !self.numbers.remove(&number) {
+            return false;
+        }
+        let impacted_lines = &self.number_to_lines[&number];
+        for &line in impacted_lines {
+            self.lines[line] -= 1;
+            if self.lines[line] == 0 {
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn calculate_unmarked_number_sum(&self) -> u64 {
+        self.numbers.iter().map(|&number| u64::from(number)).sum()
+    }
+}
+
+pub struct Solver;
+
+register_solver!(2021, 4, Solver);
+
+impl Solver {
+    /// Reads the numbers called from the first line, followed by boards
+    /// separated by blank lines.
+    fn parse_input(mut input: Box<dyn Iterator<Item = String>>) -> Result<Bingo, PuzzleError> {
+        let called_line = input.next().unwrap_or_default();
+        let line = InputLine::new(DAY, 0, &called_line);
+        let numbers_called = line
+            .token()
+            .trim()
+            .split(",")
+            .map(|number| line.parse(number, "a bingo number"))
+            .collect::<Result<Vec<u16>, PuzzleError>>()?;
+
+        let board_lines: Vec<String> = input.collect();
+        let incomplete_board =
+            |line: InputLine| line.error_at_end(format!("{BINGO_BOARD_SIZE} rows on every board"));
+        let mut numbers_to_boards: HashMap<u16, Vec<usize>> = HashMap::new();
+        let mut boards = vec![];
+        let mut bingo_board = BingoBoard::new(BINGO_BOARD_SIZE);
+        let mut row = 0;
+        for (index, board_line) in board_lines.iter().enumerate() {
+            let line = InputLine::new(DAY, index + 1, board_line);
+            if board_line.trim().is_empty() {
+                match row {
+                    0 => (),
+                    BINGO_BOARD_SIZE => boards.push(std::mem::replace(
+                        &mut bingo_board,
+                        BingoBoard::new(BINGO_BOARD_SIZE),
+                    )),
+                    _ => return Err(incomplete_board(line)),
+                }
+                row = 0;
+                continue;
+            }
+            if row == BINGO_BOARD_SIZE {
+                return Err(line.error_at(line.token().trim(), "a blank line between boards"));
+            }
+
+            let numbers: Vec<Token> = line.token().split_whitespace().collect();
+            if let Some(&extra) = numbers.get(BINGO_BOARD_SIZE) {
+                return Err(line.error_at(extra, "the end of the row"));
+            }
+            if numbers.len() < BINGO_BOARD_SIZE {
+                return Err(line.error_at_end(format!("{BINGO_BOARD_SIZE} numbers in a row")));
+            }
+            for (col, number) in numbers.into_iter().enumerate() {
+                let number = line.parse(number, "a bingo number")?;
+                bingo_board.add_number(number, row, col);
+                numbers_to_boards
+                    .entry(number)
+                    .or_default()
+                    .push(boards.len());
+            }
+            row += 1;
+        }
+        match row {
+            0 => (),
+            BINGO_BOARD_SIZE => boards.push(bingo_board),
+            _ => {
+                let index = board_lines.len();
+                let last_line = InputLine::new(DAY, index, &board_lines[index - 1]);
+                return Err(incomplete_board(last_line));
+            }
+        }
+
+        Ok(Bingo {
+            numbers_called,
+            boards,
+            numbers_to_boards,
+        })
+    }
+
+    fn puzzle_one(bingo: &Bingo) -> Result<Answer, PuzzleError> {
+        let mut boards = bingo.boards.clone();
+        for &bingo_number in &bingo.numbers_called {
+            if let Some(board_list) = bingo.numbers_to_boards.get(&bingo_number) {
+                for &board_index in board_list {
+                    let board = &mut boards[board_index];
+                    if board.mark_number_called(bingo_number) {
+                        let sum_of_uncalled_numbers = board.calculate_unmarked_number_sum();
+                        return Ok((u64::from(bingo_number) * sum_of_uncalled_numbers).into());
+                    }
+                }
+            }
+        }
+
+        Err(PuzzleError::NoSolutionFound)
+    }
+
+    fn puzzle_two(bingo: &Bingo) -> Result<Answer, PuzzleError> {
+        let mut boards = bingo.boards.clone();
+        let mut unfinished_board_set: HashSet<usize> = (0..boards.len()).collect();
+
+        let mut last_win = None;
+        for &bingo_number in &bingo.numbers_called {
+            if let Some(board_list) = bingo.numbers_to_boards.get(&bingo_number) {
+                for &board_index in board_list {
+                    let board = &mut boards[board_index];
+                    if unfinished_board_set.contains(&board_index)
+                        && board.mark_number_called(bingo_number)
+                    {
+                        unfinished_board_set.remove(&board_index);
+                        last_win = Some((board_index, bingo_number));
+                    }
+                }
+            }
+        }
+
+        let (last_winning_board_index, number_called) =
+            last_win.ok_or(PuzzleError::NoSolutionFound)?;
+        let sum_of_uncalled_numbers =
+            boards[last_winning_board_index].calculate_unmarked_number_sum();
+        Ok((u64::from(number_called) * sum_of_uncalled_numbers).into())
+    }
+}
+
+impl Solution for Solver {
+    type Input = Bingo;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        Solver::parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_input() -> Box<dyn Iterator<Item = String>> {
+        let bingo = [
+            "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1",
+            " ",
+            "22 13 17 11  0",
+            "8  2 23  4 24",
+            "21  9 14 16  7",
+            "6 10  3 18  5",
+            "1 12 20 15 19",
+            "",
+            "3 15  0  2 22",
+            "9 18 13 17  5",
+            "19  8  7 25 23",
+            "20 11 10 24  4",
+            "14 21 16 12  6",
+            "  ",
+            "14 21 17 24  4",
+            "10 16 15  9 19",
+            "18  8 23 26 20",
+            "22 11 13  6  5",
+            " 2  0 12  3  7",
+            "",
+        ];
+        Box::new(bingo.into_iter().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let bingo = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(bingo.numbers_called.len(), 27);
+        assert_eq!(bingo.boards.len(), 3);
+        assert_eq!(bingo.numbers_to_boards[&22], vec![0, 1, 2]);
+    }
+
+    fn parse_error(lines: &[&str]) -> (usize, usize, String) {
+        let input = lines
+            .iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>();
+        match Solver::parse_input(Box::new(input.into_iter())) {
+            Err(PuzzleError::ParseError {
+                line_number,
+                column,
+                expected,
+                ..
+            }) => (line_number, column, expected),
+            other => panic!("Expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_input_rejects_misshapen_boards() {
+        let rows = [
+            "1 2 3 4 5",
+            "6 7 8 9 10",
+            "11 12 13 14 15",
+            "16 17 18 19 20",
+        ];
+        let mut board = vec!["1,2", ""];
+        board.extend(rows);
+
+        // A board one row short, whether it's followed by a blank line or not
+        assert_eq!(parse_error(&board).0, 6);
+        assert_eq!(parse_error(&[&board[..], &[""]].concat()).0, 7);
+
+        board.push("21 22 23 24 25");
+        assert_eq!(
+            parse_error(&[&board[..], &["26 27 28 29 30"]].concat()),
+            (8, 1, "a blank line between boards".to_string())
+        );
+
+        board[6] = "21 22 23 24 25 26";
+        assert_eq!(
+            parse_error(&board),
+            (7, 16, "the end of the row".to_string())
+        );
+        board[6] = "21 22 23 24";
+        assert_eq!(parse_error(&board).2, "5 numbers in a row");
+    }
+
+    #[test]
+    fn test_large_numbers_do_not_overflow() {
+        let board = [
+            "65535,65534,65533,65532,65531",
+            "",
+            "65535 65534 65533 65532 65531",
+            "65530 65529 65528 65527 65526",
+            "65525 65524 65523 65522 65521",
+            "65520 65519 65518 65517 65516",
+            "65515 65514 65513 65512 65511",
+        ];
+        let input = board.into_iter().map(String::from).collect::<Vec<_>>();
+        let bingo = Solver::parse_input(Box::new(input.into_iter())).unwrap();
+        assert_eq!(bingo.boards[0].calculate_unmarked_number_sum(), 1638075);
+        assert_eq!(
+            Solver::puzzle_one(&bingo).unwrap(),
+            Answer::from(85872477710_u64)
+        );
+    }
+
+    #[test]
+    fn test_puzzle_one() {
+        let bingo = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(Solver::puzzle_one(&bingo).unwrap(), Answer::from(4512));
+    }
+
+    #[test]
+    fn test_puzzle_two() {
+        let bingo = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(Solver::puzzle_two(&bingo).unwrap(), Answer::from(1924));
+    }
+}
@@ -0,0 +1,356 @@
+use std::cmp;
+
+use crate::{
+    register_solver, Answer, Frame, InputLine, Observer, Part, PuzzleError, Solution, Token,
+};
+
+const DAY: u8 = 5;
+/// The furthest a vent may be from 0,0 in each direction, which keeps the
+/// map to at most 4096 points square. The puzzle's own vents are all
+/// within 1000.
+const MAX_COORDINATE: usize = 4095;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct VentPoint {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl VentPoint {
+    /// Parses `token`, a piece of `line`, as a `col,row` pair.
+    pub fn parse(line: &InputLine, token: Token) -> Result<Self, PuzzleError> {
+        let Some((col, row)) = token.split_once(",") else {
+            return Err(line.error_at(token, "a point, such as 0,9"));
+        };
+        Ok(Self {
+            col: Self::parse_coordinate(line, col, "a column")?,
+            row: Self::parse_coordinate(line, row, "a row")?,
+        })
+    }
+
+    fn parse_coordinate(line: &InputLine, token: Token, name: &str) -> Result<usize, PuzzleError> {
+        let coordinate = line.parse(token, name)?;
+        if coordinate > MAX_COORDINATE {
+            let expected = format!("{name} of at most {MAX_COORDINATE}");
+            return Err(line.error_at(token, expected));
+        }
+        Ok(coordinate)
+    }
+}
+
+/// Every line of vents, along with the furthest point any of them reach.
+#[derive(Debug)]
+pub struct VentLines {
+    max_col: usize,
+    max_row: usize,
+    lines: Vec<(VentPoint, VentPoint)>,
+}
+
+#[derive(Debug)]
+struct VentMap {
+    vents: Vec<Vec<u32>>,
+    vent_overlap_count: u32,
+    include_diagonals: bool,
+}
+
+impl VentMap {
+    pub fn new(num_cols: usize, num_rows: usize, include_diagonals: bool) -> Self {
+        Self {
+            vents: vec![vec![0; num_rows]; num_cols],
+            vent_overlap_count: 0,
+            include_diagonals,
+        }
+    }
+
+    fn plot_vent(&mut self, col: usize, row: usize) {
+        let vent_count = self.vents[col][row] + 1;
+        self.vents[col][row] = vent_count;
+        if vent_count == 2 {
+            self.vent_overlap_count += 1;
+        }
+    }
+
+    fn plot_additive_line(&mut self, start_point: VentPoint, end_point: VentPoint) {
+        let starting_col = cmp::min(start_point.col, end_point.col);
+        let ending_col = cmp::max(start_point.col, end_point.col);
+        let starting_row = cmp::min(start_point.row, end_point.row);
+        let ending_row = cmp::max(start_point.row, end_point.row);
+        let mut col = starting_col;
+        let mut row = starting_row;
+
+        while col < ending_col || row < ending_row {
+            self.plot_vent(col, row);
+            if col < ending_col {
+                col += 1;
+            }
+            if row < ending_row {
+                row += 1;
+            }
+        }
+
+        // Handle last entry, since bounds are inclusive
+        self.plot_vent(col, row);
+    }
+
+    fn plot_subtractive_line(&mut self, start_point: VentPoint, end_point: VentPoint) {
+        let subtract_from_rows = start_point.col < end_point.col;
+
+        let mut row = start_point.row;
+        let mut col = start_point.col;
+        if subtract_from_rows {
+            while col < end_point.col || row > end_point.row {
+                self.plot_vent(col, row);
+                if col < end_point.col {
+                    col += 1;
+                }
+                if row > end_point.row {
+                    row -= 1;
+                }
+            }
+        } else {
+            while col > end_point.col || row < end_point.row {
+                self.plot_vent(col, row);
+                if col > end_point.col {
+                    col -= 1;
+                }
+                if row < end_point.row {
+                    row += 1;
+                }
+            }
+        }
+        self.plot_vent(col, row);
+    }
+
+    pub fn add_vent_line(&mut self, start_point: VentPoint, end_point: VentPoint) {
+        if !self.include_diagonals
+            && (start_point.row != end_point.row && start_point.col != end_point.col)
+        {
+            return;
+        }
+
+        if start_point.row <= end_point.row && start_point.col <= end_point.col
+            || (start_point.row >= end_point.row && start_point.col >= end_point.col)
+        {
+            self.plot_additive_line(start_point, end_point);
+        } else {
+            self.plot_subtractive_line(start_point, end_point);
+        }
+    }
+
+    pub fn vent_overlap_count(&self) -> u32 {
+        self.vent_overlap_count
+    }
+
+    /// Draws the map the way the puzzle does, with the number of vents at
+    /// each point or `.` for none. Points with more than nine vents are `+`.
+    pub fn render(&self) -> Vec<String> {
+        let num_rows = self.vents.first().map_or(0, Vec::len);
+        (0..num_rows)
+            .map(|row| {
+                self.vents
+                    .iter()
+                    .map(|column| match column[row] {
+                        0 => '.',
+                        count => char::from_digit(count, 10).unwrap_or('+'),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+pub struct Solver;
+
+register_solver!(2021, 5, Solver);
+
+impl Solver {
+    fn parse_input(input: Box<dyn Iterator<Item = String>>) -> Result<VentLines, PuzzleError> {
+        let mut max_col = 0;
+        let mut max_row = 0;
+        let mut lines = vec![];
+        for (index, vent_line) in input.enumerate() {
+            let line = InputLine::new(DAY, index, &vent_line);
+            let Some((start, end)) = line.token().trim().split_once(" -> ") else {
+                return Err(line.error_at_end("two points separated by ->"));
+            };
+            let start_point = VentPoint::parse(&line, start)?;
+            let end_point = VentPoint::parse(&line, end)?;
+            for point in [start_point, end_point] {
+                max_col = cmp::max(max_col, point.col);
+                max_row = cmp::max(max_row, point.row);
+            }
+            lines.push((start_point, end_point));
+        }
+
+        Ok(VentLines {
+            max_col,
+            max_row,
+            lines,
+        })
+    }
+
+    /// Plots every line, showing `observer` the map after each one. Lines
+    /// are plotted whole, so every granularity gets the same frames.
+    fn count_overlaps(
+        vent_lines: &VentLines,
+        include_diagonals: bool,
+        mut observer: Option<&mut dyn Observer>,
+    ) -> u32 {
+        let mut vent_map = VentMap::new(
+            vent_lines.max_col + 1,
+            vent_lines.max_row + 1,
+            include_diagonals,
+        );
+        for &(start_point, end_point) in &vent_lines.lines {
+            vent_map.add_vent_line(start_point, end_point);
+            if let Some(observer) = observer.as_mut() {
+                let title = format!(
+                    "{},{} -> {},{}",
+                    start_point.col, start_point.row, end_point.col, end_point.row
+                );
+                observer.frame(Frame::grid(title, vent_map.render()));
+            }
+        }
+        if let Some(observer) = observer {
+            observer.frame(Frame::stats(
+                "Done",
+                [(
+                    "points with overlapping vents",
+                    vent_map.vent_overlap_count(),
+                )],
+            ));
+        }
+        vent_map.vent_overlap_count()
+    }
+
+    fn puzzle_one(vent_lines: &VentLines) -> Result<Answer, PuzzleError> {
+        Ok(Solver::count_overlaps(vent_lines, false, None).into())
+    }
+
+    fn puzzle_two(vent_lines: &VentLines) -> Result<Answer, PuzzleError> {
+        Ok(Solver::count_overlaps(vent_lines, true, None).into())
+    }
+}
+
+impl Solution for Solver {
+    type Input = VentLines;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        Solver::parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_two(input)
+    }
+
+    fn observe(
+        input: &Self::Input,
+        part: Part,
+        observer: &mut dyn Observer,
+    ) -> Option<Result<Answer, PuzzleError>> {
+        let include_diagonals = part == Part::Two;
+        Some(Ok(Solver::count_overlaps(
+            input,
+            include_diagonals,
+            Some(observer),
+        )
+        .into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observe::FrameLog;
+    use crate::Granularity;
+
+    fn get_test_input() -> Box<dyn Iterator<Item = String>> {
+        let vent_lines = [
+            "0,9 -> 5,9",
+            "8,0 -> 0,8",
+            "9,4 -> 3,4",
+            "2,2 -> 2,1",
+            "7,0 -> 7,4",
+            "6,4 -> 2,0",
+            "0,9 -> 2,9",
+            "3,4 -> 1,4",
+            "0,0 -> 8,8",
+            "5,5 -> 8,2",
+        ];
+        Box::new(vent_lines.into_iter().map(String::from))
+    }
+
+    #[test]
+    fn test_puzzle_one() {
+        let vent_lines = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(Solver::puzzle_one(&vent_lines).unwrap(), Answer::from(5));
+    }
+
+    #[test]
+    fn test_puzzle_two() {
+        let vent_lines = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(Solver::puzzle_two(&vent_lines).unwrap(), Answer::from(12));
+    }
+
+    #[test]
+    fn test_observe() {
+        let vent_lines = Solver::parse_input(get_test_input()).unwrap();
+        let mut log = FrameLog::new(Granularity::Instruction);
+        let answer = Solver::observe(&vent_lines, Part::Two, &mut log).unwrap();
+        assert_eq!(answer.unwrap(), Answer::from(12));
+        assert_eq!(log.frames.len(), 11);
+        assert_eq!(
+            log.frames[9].render(),
+            "== 5,5 -> 8,2 ==
+
+1.1....11.
+.111...2..
+..2.1.111.
+...1.2.2..
+.112313211
+...1.2....
+..1...1...
+.1.....1..
+1.......1.
+222111...."
+        );
+    }
+
+    #[test]
+    fn test_parse_input_rejects_distant_vents() {
+        let input = Box::new(
+            ["0,0 -> 4095,4095", "0,0 -> 999999,999999"]
+                .map(String::from)
+                .into_iter(),
+        );
+        match Solver::parse_input(input) {
+            Err(PuzzleError::ParseError {
+                line_number,
+                column,
+                expected,
+                ..
+            }) => {
+                assert_eq!((line_number, column), (2, 8));
+                assert_eq!(expected, "a column of at most 4095");
+            }
+            other => panic!("Expected a parse error, found {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_input_malformed() {
+        let input = Box::new(["0,9 -> 5,9", "8,0 -> 0;8"].into_iter().map(String::from));
+        assert!(matches!(
+            Solver::parse_input(input),
+            Err(PuzzleError::ParseError {
+                line_number: 2,
+                column: 8,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{register_solver, Answer, Frame, InputLine, Observer, Part, PuzzleError, Solution};
+
+const DAY: u8 = 6;
+const DEFAULT_SPAWN_DAYS: u8 = 6;
+const NEW_FISH_SPAWN_DAYS: u8 = 8;
+const PART_ONE_SIMULATION_DAYS: u32 = 80;
+const PART_TWO_SIMULATION_DAYS: u32 = 256;
+
+struct LanternFish {
+    days_to_spawn: u8,
+}
+
+impl LanternFish {
+    pub fn new() -> Self {
+        Self {
+            days_to_spawn: NEW_FISH_SPAWN_DAYS,
+        }
+    }
+
+    pub fn new_with_days_to_spawn(days_to_spawn: u8) -> Self {
+        Self { days_to_spawn }
+    }
+
+    pub fn next_day_with_spawn_check(&mut self) -> bool {
+        if self.days_to_spawn == 0 {
+            self.days_to_spawn = DEFAULT_SPAWN_DAYS;
+            return true;
+        }
+        self.days_to_spawn -= 1;
+        false
+    }
+}
+
+/// Simulates each fish individually, which is only fast enough for a few
+/// generations.
+fn count_lanternfish_individually(ages: &[u8], simulation_days: u32) -> usize {
+    let mut lanternfish_vec: Vec<LanternFish> = ages
+        .iter()
+        .copied()
+        .map(LanternFish::new_with_days_to_spawn)
+        .collect();
+    let mut new_spawns = vec![];
+    for _ in 0..simulation_days {
+        for lanternfish in lanternfish_vec.iter_mut() {
+            if lanternfish.next_day_with_spawn_check() {
+                new_spawns.push(LanternFish::new());
+            }
+        }
+        lanternfish_vec.append(&mut new_spawns)
+    }
+    lanternfish_vec.len()
+}
+
+/// Simulates how many fish there are of each age rather than each fish.
+/// `observer` is shown the count of each age after every day, since days are
+/// the only steps.
+fn count_lanternfish_by_age(
+    ages: &[u8],
+    simulation_days: u32,
+    mut observer: Option<&mut dyn Observer>,
+) -> u64 {
+    let mut lanternfish_buckets: Vec<u64> = vec![0; 9];
+
+    for &age in ages {
+        lanternfish_buckets[age as usize] += 1;
+    }
+
+    for day in 0..simulation_days {
+        let day_0_count = lanternfish_buckets[0];
+
+        // Move everything down one
+        for bucket in 0..=7 {
+            lanternfish_buckets[bucket] = lanternfish_buckets[bucket + 1];
+        }
+        lanternfish_buckets[8] = day_0_count;
+        lanternfish_buckets[6] += day_0_count;
+
+        if let Some(observer) = observer.as_mut() {
+            let total: u64 = lanternfish_buckets.iter().sum();
+            let by_age = lanternfish_buckets
+                .iter()
+                .enumerate()
+                .map(|(age, count)| (format!("age {age}"), *count));
+            observer.frame(Frame::stats(
+                format!("After {} days", day + 1),
+                [("fish".to_string(), total)].into_iter().chain(by_age),
+            ));
+        }
+    }
+
+    lanternfish_buckets.into_iter().sum::<u64>()
+}
+
+pub struct Solver;
+
+register_solver!(2021, 6, Solver);
+
+impl Solver {
+    fn parse_input(input: Box<dyn Iterator<Item = String>>) -> Result<Vec<u8>, PuzzleError> {
+        let mut ages = vec![];
+        for (index, age_line) in input.enumerate() {
+            let line = InputLine::new(DAY, index, &age_line);
+            for token in line.token().trim().split(",") {
+                let age = line.parse(token, "a lanternfish age")?;
+                if age > NEW_FISH_SPAWN_DAYS {
+                    return Err(
+                        line.error_at(token, format!("an age of at most {NEW_FISH_SPAWN_DAYS}"))
+                    );
+                }
+                ages.push(age);
+            }
+        }
+        Ok(ages)
+    }
+
+    fn puzzle_one(ages: &[u8]) -> Result<Answer, PuzzleError> {
+        Ok(count_lanternfish_individually(ages, PART_ONE_SIMULATION_DAYS).into())
+    }
+
+    fn puzzle_two(ages: &[u8]) -> Result<Answer, PuzzleError> {
+        Ok(count_lanternfish_by_age(ages, PART_TWO_SIMULATION_DAYS, None).into())
+    }
+}
+
+impl Solution for Solver {
+    type Input = Vec<u8>;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        Solver::parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_two(input)
+    }
+
+    /// Both parts are simulated by age, as simulating each fish would mean
+    /// drawing every fish.
+    fn observe(
+        input: &Self::Input,
+        part: Part,
+        observer: &mut dyn Observer,
+    ) -> Option<Result<Answer, PuzzleError>> {
+        let simulation_days = match part {
+            Part::One => PART_ONE_SIMULATION_DAYS,
+            Part::Two => PART_TWO_SIMULATION_DAYS,
+        };
+        let ages: Vec<String> = input.iter().map(u8::to_string).collect();
+        observer.frame(Frame::text("Initial State", ages.join(",")));
+        Some(Ok(count_lanternfish_by_age(
+            input,
+            simulation_days,
+            Some(observer),
+        )
+        .into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observe::FrameLog;
+    use crate::Granularity;
+
+    fn get_test_input() -> Box<dyn Iterator<Item = String>> {
+        Box::new(["3,4,3,1,2"].into_iter().map(String::from))
+    }
+
+    #[test]
+    fn test_count_lanternfish_individually_18_days() {
+        let ages = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(count_lanternfish_individually(&ages, 18), 26);
+    }
+
+    #[test]
+    fn test_puzzle_one() {
+        let ages = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(Solver::puzzle_one(&ages).unwrap(), Answer::from(5934));
+    }
+
+    #[test]
+    fn test_puzzle_two() {
+        let ages = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(count_lanternfish_by_age(&ages, 80, None), 5934);
+        assert_eq!(
+            Solver::puzzle_two(&ages).unwrap(),
+            Answer::from(26984457539u64)
+        );
+    }
+
+    #[test]
+    fn test_observe() {
+        let ages = Solver::parse_input(get_test_input()).unwrap();
+        let mut log = FrameLog::new(Granularity::Step);
+        let answer = Solver::observe(&ages, Part::One, &mut log).unwrap();
+        assert_eq!(answer.unwrap(), Answer::from(5934));
+        assert_eq!(log.frames.len(), 81);
+        assert_eq!(log.frames[0], Frame::text("Initial State", "3,4,3,1,2"));
+        // After 18 days: 6,0,6,4,5,6,0,1,1,2,6,0,1,1,1,2,2,3,3,4,6,7,8,8,8,8
+        let Frame::Stats { title, stats } = &log.frames[18] else {
+            panic!("Expected stats, found {:?}", log.frames[18]);
+        };
+        assert_eq!(title, "After 18 days");
+        assert_eq!(stats[0], ("fish".to_string(), "26".to_string()));
+        assert_eq!(stats[1], ("age 0".to_string(), "3".to_string()));
+    }
+}
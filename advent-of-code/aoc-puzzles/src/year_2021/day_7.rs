@@ -0,0 +1,129 @@
+use std::cmp;
+use std::collections::HashSet;
+
+use crate::{register_solver, Answer, InputLine, PuzzleError, Solution};
+
+const DAY: u8 = 7;
+
+fn calculate_scaling_fuel_cost(convergence_point: u32, crab_positions: &[u32]) -> (u32, u32, u32) {
+    let convergence_point = convergence_point as i32;
+
+    let mut left_fuel_cost = 0;
+    let mut mid_fuel_cost = 0;
+    let mut right_fuel_cost = 0;
+    for &position in crab_positions {
+        let position = position as i32;
+        let distance_to_convergence_point = (convergence_point - position).abs();
+
+        mid_fuel_cost += distance_to_convergence_point * (distance_to_convergence_point + 1) / 2;
+
+        let left_distance = (convergence_point - position - 1).abs();
+        left_fuel_cost += left_distance * (left_distance + 1) / 2;
+
+        let right_distance = (convergence_point - position + 1).abs();
+        right_fuel_cost += right_distance * (right_distance + 1) / 2;
+    }
+
+    (
+        left_fuel_cost as u32,
+        mid_fuel_cost as u32,
+        right_fuel_cost as u32,
+    )
+}
+
+pub struct Solver;
+
+register_solver!(2021, 7, Solver);
+
+impl Solver {
+    fn parse_input(input: Box<dyn Iterator<Item = String>>) -> Result<Vec<u32>, PuzzleError> {
+        let mut crab_positions = vec![];
+        for (index, position_line) in input.enumerate() {
+            let line = InputLine::new(DAY, index, &position_line);
+            for position in line.token().trim().split(",") {
+                crab_positions.push(line.parse(position, "a crab position")?);
+            }
+        }
+        Ok(crab_positions)
+    }
+
+    fn puzzle_one(crab_positions: &[u32]) -> Result<Answer, PuzzleError> {
+        let possible_positions: HashSet<u32> = crab_positions.iter().copied().collect();
+        let mut min_fuel = u32::MAX;
+        for position in possible_positions {
+            let mut fuel_for_position = 0;
+            for &crab_position in crab_positions {
+                fuel_for_position += position.abs_diff(crab_position);
+                if fuel_for_position >= min_fuel {
+                    break;
+                }
+            }
+            min_fuel = cmp::min(fuel_for_position, min_fuel);
+        }
+        Ok(min_fuel.into())
+    }
+
+    fn puzzle_two(crab_positions: &[u32]) -> Result<Answer, PuzzleError> {
+        let mut min_position = crab_positions.iter().copied().min().unwrap_or(0);
+        let mut max_position = crab_positions.iter().copied().max().unwrap_or(0);
+
+        while min_position <= max_position {
+            let mid_point = min_position + (max_position - min_position) / 2;
+            let (left_fuel, mid_fuel, right_fuel) =
+                calculate_scaling_fuel_cost(mid_point, crab_positions);
+
+            if mid_fuel <= left_fuel && mid_fuel <= right_fuel {
+                return Ok(mid_fuel.into());
+            }
+            if mid_fuel > left_fuel {
+                max_position = mid_point;
+            } else {
+                min_position = mid_point;
+            }
+        }
+        Err(PuzzleError::NoSolutionFound)
+    }
+}
+
+impl Solution for Solver {
+    type Input = Vec<u32>;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        Solver::parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_input() -> Box<dyn Iterator<Item = String>> {
+        Box::new(["16,1,2,0,4,2,7,1,2,14"].into_iter().map(String::from))
+    }
+
+    #[test]
+    fn test_puzzle_one() {
+        let crab_positions = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(
+            Solver::puzzle_one(&crab_positions).unwrap(),
+            Answer::from(37)
+        );
+    }
+
+    #[test]
+    fn test_puzzle_two() {
+        let crab_positions = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(
+            Solver::puzzle_two(&crab_positions).unwrap(),
+            Answer::from(168)
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{register_solver, Answer, InputLine, Part, PuzzleError, Solution};
+
+const DAY: u8 = 8;
+const SIGNAL_PATTERN_COUNT: usize = 10;
+const OUTPUT_DIGIT_COUNT: usize = 4;
+
+/// The segments lit by a pattern, one bit for each of `a` through `g`.
+type Segments = u8;
+
+fn segments(pattern: &str) -> Segments {
+    pattern
+        .bytes()
+        .fold(0, |segments, wire| segments | 1 << (wire - b'a'))
+}
+
+#[derive(Debug, Default)]
+pub struct DisplayOutput {
+    signal_patterns: Vec<String>,
+    output: Vec<String>,
+}
+
+impl DisplayOutput {
+    pub fn add_signal_pattern(&mut self, pattern: String) {
+        self.signal_patterns.push(pattern);
+    }
+
+    pub fn add_output(&mut self, output: String) {
+        self.output.push(output);
+    }
+
+    pub fn output(&self) -> &Vec<String> {
+        &self.output
+    }
+
+    /// Works out which pattern lights each digit. 1, 4, 7 and 8 are the only
+    /// digits with their number of segments, and the rest can be told apart
+    /// by how many segments they share with 1 and 4.
+    fn decode_patterns(&self) -> Result<[Segments; 10], PuzzleError> {
+        let patterns: Vec<Segments> = self.signal_patterns.iter().map(|p| segments(p)).collect();
+        let with_len = |len: u32| {
+            patterns
+                .iter()
+                .copied()
+                .filter(move |p| p.count_ones() == len)
+        };
+        let unique = |len: u32, digit: &str| {
+            with_len(len)
+                .next()
+                .ok_or_else(|| PuzzleError::DataConsistencyError {
+                    day: DAY,
+                    part: Part::Two,
+                    expected: format!("a pattern for {digit} with {len} segments"),
+                    found: format!("{:?}", self.signal_patterns),
+                })
+        };
+        let one = unique(2, "1")?;
+        let four = unique(4, "4")?;
+
+        let mut digits = [0; 10];
+        digits[1] = one;
+        digits[4] = four;
+        digits[7] = unique(3, "7")?;
+        digits[8] = unique(7, "8")?;
+        for pattern in with_len(6) {
+            let digit = if pattern & four == four {
+                9
+            } else if pattern & one == one {
+                0
+            } else {
+                6
+            };
+            digits[digit] = pattern;
+        }
+        for pattern in with_len(5) {
+            let digit = if pattern & one == one {
+                3
+            } else if (pattern & four).count_ones() == 3 {
+                5
+            } else {
+                2
+            };
+            digits[digit] = pattern;
+        }
+        Ok(digits)
+    }
+
+    /// Reads the four output digits as a number.
+    pub fn output_value(&self) -> Result<u32, PuzzleError> {
+        let digits = self.decode_patterns()?;
+        let mut value = 0;
+        for output in &self.output {
+            let lit = segments(output);
+            let digit = digits
+                .iter()
+                .position(|&digit| digit == lit)
+                .ok_or_else(|| PuzzleError::DataConsistencyError {
+                    day: DAY,
+                    part: Part::Two,
+                    expected: "an output digit matching a signal pattern".to_string(),
+                    found: output.clone(),
+                })?;
+            value = value * 10 + digit as u32;
+        }
+        Ok(value)
+    }
+}
+
+pub struct Solver;
+
+register_solver!(2021, 8, Solver);
+
+impl Solver {
+    fn parse_input(
+        input: Box<dyn Iterator<Item = String>>,
+    ) -> Result<Vec<DisplayOutput>, PuzzleError> {
+        let mut digits = vec![];
+        for (index, display_line) in input.enumerate() {
+            let line = InputLine::new(DAY, index, &display_line);
+            let mut in_output_section = false;
+            let mut display_output = DisplayOutput::default();
+            for token in line.token().split_whitespace() {
+                let piece = token.text();
+                if piece == "|" {
+                    in_output_section = true;
+                    continue;
+                }
+                if piece.is_empty() || !piece.bytes().all(|wire| (b'a'..=b'g').contains(&wire)) {
+                    return Err(line.error_at(token, "a pattern of wires a through g"));
+                }
+                if in_output_section {
+                    display_output.add_output(piece.to_string());
+                } else {
+                    display_output.add_signal_pattern(piece.to_string());
+                }
+            }
+            if display_output.signal_patterns.len() != SIGNAL_PATTERN_COUNT
+                || display_output.output.len() != OUTPUT_DIGIT_COUNT
+            {
+                return Err(line.error(
+                    1,
+                    format!(
+                        "{SIGNAL_PATTERN_COUNT} signal patterns, | and {OUTPUT_DIGIT_COUNT} output digits"
+                    ),
+                    display_line.as_str(),
+                ));
+            }
+            digits.push(display_output);
+        }
+        Ok(digits)
+    }
+
+    fn puzzle_one(display_outputs: &[DisplayOutput]) -> Result<Answer, PuzzleError> {
+        let mut seen_digits = 0;
+        for display_output in display_outputs {
+            for digits in display_output.output() {
+                match digits.len() {
+                    2 | 3 | 4 | 7 => seen_digits += 1,
+                    _ => continue,
+                }
+            }
+        }
+        Ok(seen_digits.into())
+    }
+
+    fn puzzle_two(display_outputs: &[DisplayOutput]) -> Result<Answer, PuzzleError> {
+        let mut sum = 0;
+        for display_output in display_outputs {
+            sum += display_output.output_value()?;
+        }
+        Ok(sum.into())
+    }
+}
+
+impl Solution for Solver {
+    type Input = Vec<DisplayOutput>;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        Solver::parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_input() -> Box<dyn Iterator<Item = String>> {
+        let displays = [
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe",
+            "edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc",
+            "fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg",
+            "fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb",
+            "aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea",
+            "fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb",
+            "dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe",
+            "bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef",
+            "egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb",
+            "gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce",
+        ];
+        Box::new(displays.into_iter().map(String::from))
+    }
+
+    #[test]
+    fn test_puzzle_one() {
+        let display_outputs = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(
+            Solver::puzzle_one(&display_outputs).unwrap(),
+            Answer::from(26)
+        );
+    }
+
+    #[test]
+    fn test_output_value() {
+        let input = Box::new(
+            ["acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf"]
+                .into_iter()
+                .map(String::from),
+        );
+        let display_outputs = Solver::parse_input(input).unwrap();
+        assert_eq!(display_outputs[0].output_value().unwrap(), 5353);
+    }
+
+    #[test]
+    fn test_puzzle_two() {
+        let display_outputs = Solver::parse_input(get_test_input()).unwrap();
+        assert_eq!(
+            Solver::puzzle_two(&display_outputs).unwrap(),
+            Answer::from(61229)
+        );
+    }
+
+    #[test]
+    fn test_parse_input_malformed() {
+        let input = Box::new(["be cfbegad | fdgacbe"].into_iter().map(String::from));
+        assert!(matches!(
+            Solver::parse_input(input),
+            Err(PuzzleError::ParseError { line_number: 1, .. })
+        ));
+        let input = Box::new(
+            ["be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edx | fdgacbe cefdb cefbgd gcbe"]
+                .into_iter()
+                .map(String::from),
+        );
+        assert!(matches!(
+            Solver::parse_input(input),
+            Err(PuzzleError::ParseError { column: 56, .. })
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::BinaryHeap;
+
+use crate::{register_solver, Answer, InputLine, Part, PuzzleError, Solution};
+
+const DAY: u8 = 1;
+
+pub struct Solver;
+
+register_solver!(2022, 1, Solver);
+
+impl Solver {
+    /// Groups the calorie counts carried by each elf, which are separated by blank lines.
+    fn parse_input(input: Box<dyn Iterator<Item = String>>) -> Result<Vec<Vec<i32>>, PuzzleError> {
+        let mut elves = vec![];
+        let mut elf = vec![];
+        for (index, value) in input.enumerate() {
+            if value.is_empty() {
+                elves.push(std::mem::take(&mut elf));
+            } else {
+                let line = InputLine::new(DAY, index, &value);
+                let value: i32 = line.parse(line.token(), "a calorie count")?;
+                elf.push(value);
+            }
+        }
+        if !elf.is_empty() {
+            elves.push(elf);
+        }
+        Ok(elves)
+    }
+
+    fn puzzle_one(elves: &[Vec<i32>]) -> Result<Answer, PuzzleError> {
+        let mut max = 0;
+        for elf in elves {
+            let sum: i32 = elf.iter().sum();
+            max = std::cmp::max(max, sum);
+        }
+        Ok(max.into())
+    }
+
+    fn puzzle_two(elves: &[Vec<i32>]) -> Result<Answer, PuzzleError> {
+        const TOP_ELVES: usize = 3;
+        if elves.len() < TOP_ELVES {
+            return Err(PuzzleError::DataConsistencyError {
+                day: DAY,
+                part: Part::Two,
+                expected: format!("at least {TOP_ELVES} elves"),
+                found: format!("{} elves", elves.len()),
+            });
+        }
+        let mut heap: BinaryHeap<i32> = elves.iter().map(|elf| elf.iter().sum()).collect();
+        let mut sum = 0;
+        for _ in 0..TOP_ELVES {
+            sum += heap.pop().unwrap_or_default();
+        }
+        Ok(sum.into())
+    }
+}
+
+impl Solution for Solver {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        Solver::parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Vec<Vec<i32>> {
+        Solver::parse_input(Box::new(
+            text.lines()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+                .into_iter(),
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_puzzles() {
+        let elves = parse("1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000");
+        assert_eq!(Solver::puzzle_one(&elves).unwrap(), Answer::from(24000));
+        assert_eq!(Solver::puzzle_two(&elves).unwrap(), Answer::from(45000));
+    }
+
+    #[test]
+    fn test_puzzle_two_needs_three_elves() {
+        let elves = parse("100\n\n200");
+        assert_eq!(Solver::puzzle_one(&elves).unwrap(), Answer::from(200));
+        assert!(matches!(
+            Solver::puzzle_two(&elves),
+            Err(PuzzleError::DataConsistencyError {
+                part: Part::Two,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use crate::{register_solver, Answer, InputLine, PuzzleError, Solution};
+
+const DAY: u8 = 2;
+
+#[derive(Debug, Clone, Copy)]
+enum GameRes {
+    Win,
+    Loss,
+    Tie,
+}
+
+pub struct Solver;
+
+register_solver!(2022, 2, Solver);
+
+impl Solver {
+    /// Splits each round into the enemy's letter and my letter.
+    fn parse_input(
+        input: Box<dyn Iterator<Item = String>>,
+    ) -> Result<Vec<(String, String)>, PuzzleError> {
+        let mut rounds = vec![];
+        for (index, text) in input.enumerate() {
+            let line = InputLine::new(DAY, index, &text);
+            let (enemy_letter, my_letter) = line.split_once(" ", "a space between letters")?;
+            if !matches!(enemy_letter.text(), "A" | "B" | "C") {
+                return Err(line.error_at(enemy_letter, "one of A, B or C"));
+            }
+            if !matches!(my_letter.text(), "X" | "Y" | "Z") {
+                return Err(line.error_at(my_letter, "one of X, Y or Z"));
+            }
+            rounds.push((
+                enemy_letter.text().to_string(),
+                my_letter.text().to_string(),
+            ));
+        }
+        Ok(rounds)
+    }
+
+    pub fn puzzle_one(input: &[(String, String)]) -> Result<Answer, PuzzleError> {
+        let values = HashMap::from([("X", 1), ("Y", 2), ("Z", 3)]);
+        let mut score = 0;
+        for (enemy_letter, my_letter) in input {
+            let enemy_letter = enemy_letter.as_str();
+            let my_letter = my_letter.as_str();
+            let game_res = match (enemy_letter, my_letter) {
+                ("A", "X") => GameRes::Tie,
+                ("B", "Y") => GameRes::Tie,
+                ("C", "Z") => GameRes::Tie,
+                ("A", "Y") => GameRes::Win,
+                ("B", "Z") => GameRes::Win,
+                ("C", "X") => GameRes::Win,
+                ("A", "Z") => GameRes::Loss,
+                ("B", "X") => GameRes::Loss,
+                ("C", "Y") => GameRes::Loss,
+                _ => unreachable!("Letters are validated while parsing"),
+            };
+            let res_score = match game_res {
+                GameRes::Win => 6,
+                GameRes::Loss => 0,
+                GameRes::Tie => 3,
+            };
+            score += values[my_letter] + res_score;
+        }
+        Ok(score.into())
+    }
+
+    fn puzzle_two(input: &[(String, String)]) -> Result<Answer, PuzzleError> {
+        let values = HashMap::from([("X", 1), ("Y", 2), ("Z", 3)]);
+        let mut score = 0;
+        for (enemy_letter, my_letter) in input {
+            let enemy_letter = enemy_letter.as_str();
+            let my_letter = my_letter.as_str();
+            let game_res = match my_letter {
+                "X" => GameRes::Loss,
+                "Y" => GameRes::Tie,
+                "Z" => GameRes::Win,
+                _ => unreachable!("Letters are validated while parsing"),
+            };
+            let play_score = match (game_res, enemy_letter) {
+                (GameRes::Win, "A") => values["Y"],
+                (GameRes::Win, "B") => values["Z"],
+                (GameRes::Win, "C") => values["X"],
+                (GameRes::Tie, "A") => values["X"],
+                (GameRes::Tie, "B") => values["Y"],
+                (GameRes::Tie, "C") => values["Z"],
+                (GameRes::Loss, "A") => values["Z"],
+                (GameRes::Loss, "B") => values["X"],
+                (GameRes::Loss, "C") => values["Y"],
+                _ => unreachable!("Letters are validated while parsing"),
+            };
+            let res_score = match game_res {
+                GameRes::Win => 6,
+                GameRes::Loss => 0,
+                GameRes::Tie => 3,
+            };
+            score += play_score + res_score;
+        }
+        Ok(score.into())
+    }
+}
+
+impl Solution for Solver {
+    type Input = Vec<(String, String)>;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        Solver::parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        Solver::puzzle_two(input)
+    }
+}
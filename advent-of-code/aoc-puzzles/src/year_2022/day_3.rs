@@ -1,6 +1,8 @@
 use std::collections::*;
 
-use crate::{Part, Puzzle, PuzzleError};
+use crate::{register_solver, Answer, InputLine, Part, PuzzleError, Solution};
+
+const DAY: u8 = 3;
 
 fn init_hashmaps() -> HashMap<char, i32> {
     HashMap::from([
@@ -61,27 +63,47 @@ fn init_hashmaps() -> HashMap<char, i32> {
 
 pub struct Solver;
 
-impl Puzzle for Solver {
-    fn new() -> Box<Self>
-    where
-        Self: Sized,
-    {
-        Box::new(Self)
-    }
+register_solver!(2022, 3, Solver);
+
+impl Solution for Solver {
+    type Input = Vec<String>;
 
-    fn solve(
-        &self,
-        input: Box<dyn Iterator<Item = String>>,
-        part: Part,
-    ) -> Result<String, PuzzleError> {
-        match part {
-            Part::One => puzzle_one(input),
-            Part::Two => puzzle_two(input),
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        let mut rucksacks = vec![];
+        for (index, text) in input.enumerate() {
+            let line = InputLine::new(DAY, index, &text);
+            if let Some((column, letter)) = text
+                .chars()
+                .enumerate()
+                .find(|(_, letter)| !letter.is_ascii_alphabetic())
+            {
+                return Err(line.error(column + 1, "a letter", letter));
+            }
+            rucksacks.push(text);
         }
+        Ok(rucksacks)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        puzzle_two(input)
+    }
+}
+
+/// Every rucksack (or group of rucksacks) is guaranteed to share an item.
+fn missing_item(part: Part, line: &str) -> PuzzleError {
+    PuzzleError::DataConsistencyError {
+        day: DAY,
+        part,
+        expected: "an item shared by the rucksacks".to_string(),
+        found: line.to_string(),
     }
 }
 
-fn puzzle_one(input: Box<dyn Iterator<Item = String>>) -> Result<String, PuzzleError> {
+fn puzzle_one(input: &[String]) -> Result<Answer, PuzzleError> {
     let mut ans = 0;
     let map = init_hashmaps();
     for line in input {
@@ -89,26 +111,28 @@ fn puzzle_one(input: Box<dyn Iterator<Item = String>>) -> Result<String, PuzzleE
         let midpoint = letters.len() / 2;
         let first_half: HashSet<char> = letters.iter().cloned().take(midpoint).collect();
         let second_half: HashSet<char> = letters[midpoint..].iter().cloned().collect();
-        let overlap: Vec<char> = first_half.intersection(&second_half).cloned().collect();
-        let overlap_char = overlap[0];
-        println!("{}", overlap_char);
-        ans += map[&overlap_char];
+        let Some(overlap_char) = first_half.intersection(&second_half).next() else {
+            return Err(missing_item(Part::One, line));
+        };
+        ans += map[overlap_char];
     }
-    Ok(ans.to_string())
+    Ok(ans.into())
 }
 
-pub fn puzzle_two(input: Box<dyn Iterator<Item = String>>) -> Result<String, PuzzleError> {
+pub fn puzzle_two(input: &[String]) -> Result<Answer, PuzzleError> {
     let mut ans = 0;
     let map = init_hashmaps();
     let mut group: [HashSet<char>; 3] = [HashSet::new(), HashSet::new(), HashSet::new()];
-    for (index, line) in input.enumerate() {
+    for (index, line) in input.iter().enumerate() {
         let letters: HashSet<char> = line.chars().collect();
         group[index % 3] = letters;
         if (index + 1) % 3 == 0 {
             let intersect_1: HashSet<char> = group[0].intersection(&group[1]).cloned().collect();
-            let intersect_2: Vec<char> = intersect_1.intersection(&group[2]).cloned().collect();
-            ans += map[&intersect_2[0]];
+            let Some(badge) = intersect_1.intersection(&group[2]).next() else {
+                return Err(missing_item(Part::Two, line));
+            };
+            ans += map[badge];
         }
     }
-    Ok(ans.to_string())
+    Ok(ans.into())
 }
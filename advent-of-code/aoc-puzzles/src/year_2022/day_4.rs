@@ -1,24 +1,27 @@
-use crate::{Part, Puzzle, PuzzleError};
+use crate::{register_solver, Answer, InputLine, PuzzleError, Solution, Token};
+
+const DAY: u8 = 4;
 
 pub struct Solver;
 
-impl Puzzle for Solver {
-    fn new() -> Box<Self>
-    where
-        Self: Sized,
-    {
-        Box::new(Self)
+register_solver!(2022, 4, Solver);
+
+impl Solution for Solver {
+    type Input = Vec<ElfPair>;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        input
+            .enumerate()
+            .map(|(index, text)| ElfPair::parse(&InputLine::new(DAY, index, &text)))
+            .collect()
     }
 
-    fn solve(
-        &self,
-        input: Box<dyn Iterator<Item = String>>,
-        part: Part,
-    ) -> Result<String, PuzzleError> {
-        match part {
-            Part::One => puzzle_one(input),
-            Part::Two => puzzle_two(input),
-        }
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        puzzle_two(input)
     }
 }
 
@@ -37,7 +40,7 @@ impl Range {
     }
 
     fn overlaps_with(&self, other: &Self) -> bool {
-        if other.contains(&self) {
+        if other.contains(self) {
             return true;
         }
         if self.start <= other.start && self.end >= other.start {
@@ -50,52 +53,54 @@ impl Range {
     }
 }
 
-struct ElfPair {
+impl Range {
+    /// Parses a `start-end` range from `token`, a piece of `line`.
+    fn parse(line: &InputLine, token: Token) -> Result<Self, PuzzleError> {
+        let (start_point, end_point) = token
+            .split_once("-")
+            .ok_or_else(|| line.error_at(token, "a range like 2-4"))?;
+        Ok(Range {
+            start: line.parse(start_point, "a section number")?,
+            end: line.parse(end_point, "a section number")?,
+        })
+    }
+}
+
+pub struct ElfPair {
     range_1: Range,
     range_2: Range,
 }
 
-impl TryFrom<&str> for ElfPair {
-    type Error = &'static str;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let mut ranges = value.split(',').map(|str_pair| {
-            let mut range = str_pair.split('-');
-            let start_point: i32 = range.next().unwrap().parse().unwrap();
-            let end_point: i32 = range.next().unwrap().parse().unwrap();
-            Range {
-                start: start_point,
-                end: end_point,
-            }
-        });
-        let range_1 = ranges.next().unwrap();
-        let range_2 = ranges.next().unwrap();
-        Ok(ElfPair { range_1, range_2 })
+impl ElfPair {
+    fn parse(line: &InputLine) -> Result<Self, PuzzleError> {
+        let (range_1, range_2) = line.split_once(",", "a comma between ranges")?;
+        Ok(ElfPair {
+            range_1: Range::parse(line, range_1)?,
+            range_2: Range::parse(line, range_2)?,
+        })
     }
 }
 
-fn puzzle_one(input: Box<dyn Iterator<Item = String>>) -> Result<String, PuzzleError> {
+fn puzzle_one(input: &[ElfPair]) -> Result<Answer, PuzzleError> {
     let mut ans = 0;
-    for line in input {
-        let elf_pair = ElfPair::try_from(line.as_str()).unwrap();
+    for elf_pair in input {
         if elf_pair.range_1.contains(&elf_pair.range_2)
             || elf_pair.range_2.contains(&elf_pair.range_1)
         {
             ans += 1;
         }
     }
-    Ok(ans.to_string())
+    Ok(ans.into())
 }
 
-fn puzzle_two(input: Box<dyn Iterator<Item = String>>) -> Result<String, PuzzleError> {
+fn puzzle_two(input: &[ElfPair]) -> Result<Answer, PuzzleError> {
     let mut ans = 0;
-    for line in input {
-        let elf_pair = ElfPair::try_from(line.as_str()).unwrap();
+    for elf_pair in input {
         if elf_pair.range_1.overlaps_with(&elf_pair.range_2) {
             ans += 1;
         }
     }
-    Ok(ans.to_string())
+    Ok(ans.into())
 }
 
 #[cfg(test)]
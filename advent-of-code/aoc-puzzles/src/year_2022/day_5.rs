@@ -0,0 +1,295 @@
+use std::collections::*;
+
+use crate::{
+    register_solver, Answer, Frame, Granularity, InputLine, Observer, Part, PuzzleError, Solution,
+};
+
+const DAY: u8 = 5;
+
+#[derive(Debug, Clone, Copy)]
+struct Move {
+    amount: usize,
+    source_stack: usize,
+    dest_stack: usize,
+}
+
+/// The starting crate stacks along with the rearrangement procedure.
+#[derive(Debug, Clone, Default)]
+pub struct CrateStacks {
+    stacks: Vec<VecDeque<char>>,
+    moves: Vec<Move>,
+}
+
+impl CrateStacks {
+    fn parse_grid_line(&mut self, line: &InputLine) -> Result<(), PuzzleError> {
+        let chars: Vec<char> = line.text().chars().collect();
+        for (stack_pos, maybe_crate) in chars.chunks(4).enumerate() {
+            // Stacks are created as they're seen, so ragged lines still line up
+            if self.stacks.len() <= stack_pos {
+                self.stacks.push(VecDeque::new());
+            }
+            match maybe_crate {
+                // Actual crate. Push it to the bottom of the stack
+                ['[', crate_id, ']', ..] if crate_id.is_ascii_alphabetic() => {
+                    self.stacks[stack_pos].push_front(*crate_id);
+                }
+                blank if blank.iter().all(|letter| *letter == ' ') => (),
+                _ => {
+                    let found: String = maybe_crate.iter().take(3).collect();
+                    return Err(line.error(
+                        stack_pos * 4 + 1,
+                        "a crate like [A] or a blank",
+                        found,
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_move_line(&mut self, line: &InputLine) -> Result<(), PuzzleError> {
+        let mut line_pieces = line.token().split(" ");
+        let mut next_piece = |expected: &str| {
+            line_pieces
+                .next()
+                .ok_or_else(|| line.error_at_end(expected))
+        };
+        let mut values = [0; 3];
+        for (word, value) in ["move", "from", "to"].into_iter().zip(values.iter_mut()) {
+            let piece = next_piece(word)?;
+            if piece.text() != word {
+                return Err(line.error_at(piece, format!("\"{word}\"")));
+            }
+            let piece = next_piece("a number")?;
+            *value = line.parse::<usize>(piece, "a number")?;
+            if word != "move" && *value == 0 {
+                return Err(line.error_at(piece, "a stack number starting from 1"));
+            }
+        }
+        if let Some(piece) = line_pieces.next() {
+            return Err(line.error_at(piece, "end of line"));
+        }
+        let [amount, source_stack, dest_stack] = values;
+        self.moves.push(Move {
+            amount,
+            source_stack: source_stack - 1,
+            dest_stack: dest_stack - 1,
+        });
+        Ok(())
+    }
+
+    pub fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self, PuzzleError> {
+        let mut crate_stacks = Self::default();
+        for (index, text) in input.enumerate() {
+            let line = InputLine::new(DAY, index, &text);
+            if text.trim_start().starts_with('[') {
+                crate_stacks.parse_grid_line(&line)?;
+            } else if text.starts_with('m') {
+                crate_stacks.parse_move_line(&line)?;
+            }
+        }
+        // Moves may target stacks that start out empty
+        let stack_count = crate_stacks
+            .moves
+            .iter()
+            .map(|crate_move| crate_move.source_stack.max(crate_move.dest_stack) + 1)
+            .max()
+            .unwrap_or(0);
+        if crate_stacks.stacks.len() < stack_count {
+            crate_stacks.stacks.resize(stack_count, VecDeque::new());
+        }
+        Ok(crate_stacks)
+    }
+
+    /// Applies every move to a copy of the stacks and returns the top crate of each.
+    /// `observer` is shown the stacks after every move, or every crate moved
+    /// at `Granularity::Step` when crates are moved one at a time.
+    fn rearrange(
+        &self,
+        part: Part,
+        preserve_crate_order_on_move: bool,
+        mut observer: Option<&mut dyn Observer>,
+    ) -> Result<String, PuzzleError> {
+        let mut stacks = self.stacks.clone();
+        if let Some(observer) = observer.as_mut() {
+            observer.frame(Frame::grid("Initial State", render_stacks(&stacks)));
+        }
+        for crate_move in &self.moves {
+            let source = &mut stacks[crate_move.source_stack];
+            if source.len() < crate_move.amount {
+                return Err(PuzzleError::DataConsistencyError {
+                    day: DAY,
+                    part,
+                    expected: format!(
+                        "at least {} crates on stack {}",
+                        crate_move.amount,
+                        crate_move.source_stack + 1
+                    ),
+                    found: format!("{} crates", source.len()),
+                });
+            }
+            let title = format!(
+                "move {} from {} to {}",
+                crate_move.amount,
+                crate_move.source_stack + 1,
+                crate_move.dest_stack + 1
+            );
+            if preserve_crate_order_on_move {
+                let crates: Vec<char> = source.drain(source.len() - crate_move.amount..).collect();
+                stacks[crate_move.dest_stack].extend(crates);
+            } else {
+                for moved in 1..=crate_move.amount {
+                    let top = stacks[crate_move.source_stack]
+                        .pop_back()
+                        .expect("The source stack was checked to hold enough crates");
+                    stacks[crate_move.dest_stack].push_back(top);
+                    match observer.as_mut() {
+                        Some(observer) if observer.granularity() == Granularity::Step => {
+                            observer.frame(Frame::grid(
+                                format!("{title} (crate {moved} of {})", crate_move.amount),
+                                render_stacks(&stacks),
+                            ));
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            match observer.as_mut() {
+                Some(observer)
+                    if preserve_crate_order_on_move
+                        || observer.granularity() == Granularity::Instruction =>
+                {
+                    observer.frame(Frame::grid(title, render_stacks(&stacks)));
+                }
+                _ => (),
+            }
+        }
+
+        let mut output = String::with_capacity(stacks.len());
+        for stack in &stacks {
+            if let Some(top) = stack.back() {
+                output.push(*top);
+            }
+        }
+        Ok(output)
+    }
+}
+
+/// Draws the stacks the way the puzzle does, with each stack's number under it.
+fn render_stacks(stacks: &[VecDeque<char>]) -> Vec<String> {
+    let height = stacks.iter().map(VecDeque::len).max().unwrap_or(0);
+    let mut rows: Vec<String> = (0..height)
+        .rev()
+        .map(|level| {
+            let cells: Vec<String> = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(crate_id) => format!("[{crate_id}]"),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            cells.join(" ").trim_end().to_string()
+        })
+        .collect();
+    let numbers: Vec<String> = (1..=stacks.len())
+        .map(|number| format!(" {number} "))
+        .collect();
+    rows.push(numbers.join(" ").trim_end().to_string());
+    rows
+}
+
+fn puzzle_one(crate_stacks: &CrateStacks) -> Result<Answer, PuzzleError> {
+    Ok(crate_stacks.rearrange(Part::One, false, None)?.into())
+}
+
+fn puzzle_two(crate_stacks: &CrateStacks) -> Result<Answer, PuzzleError> {
+    Ok(crate_stacks.rearrange(Part::Two, true, None)?.into())
+}
+
+pub struct Solver;
+
+register_solver!(2022, 5, Solver);
+
+impl Solution for Solver {
+    type Input = CrateStacks;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        CrateStacks::parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        puzzle_two(input)
+    }
+
+    fn observe(
+        input: &Self::Input,
+        part: Part,
+        observer: &mut dyn Observer,
+    ) -> Option<Result<Answer, PuzzleError>> {
+        let preserve_crate_order_on_move = part == Part::Two;
+        let answer = input.rearrange(part, preserve_crate_order_on_move, Some(observer));
+        Some(answer.map(Answer::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observe::FrameLog;
+
+    fn get_test_input() -> Box<dyn Iterator<Item = String>> {
+        let test_data = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+        Box::new(
+            test_data
+                .lines()
+                .map(String::from)
+                .collect::<Vec<_>>()
+                .into_iter(),
+        )
+    }
+
+    #[test]
+    fn test_puzzles() {
+        let crate_stacks = CrateStacks::parse(get_test_input()).unwrap();
+        assert_eq!(puzzle_one(&crate_stacks).unwrap(), Answer::from("CMZ"));
+        assert_eq!(puzzle_two(&crate_stacks).unwrap(), Answer::from("MCD"));
+    }
+
+    #[test]
+    fn test_observe() {
+        let crate_stacks = CrateStacks::parse(get_test_input()).unwrap();
+        let mut log = FrameLog::new(Granularity::Step);
+        let answer = Solver::observe(&crate_stacks, Part::One, &mut log).unwrap();
+        assert_eq!(answer.unwrap(), Answer::from("CMZ"));
+        // The initial state and one frame per crate moved
+        assert_eq!(log.frames.len(), 8);
+        assert_eq!(
+            log.frames[1].render(),
+            "== move 1 from 2 to 1 (crate 1 of 1) ==
+
+[D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3"
+        );
+
+        let mut log = FrameLog::new(Granularity::Step);
+        Solver::observe(&crate_stacks, Part::Two, &mut log)
+            .unwrap()
+            .unwrap();
+        assert_eq!(log.frames.len(), 5);
+        assert_eq!(log.frames[2].title(), "move 3 from 1 to 3");
+    }
+}
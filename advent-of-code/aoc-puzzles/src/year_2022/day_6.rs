@@ -1,6 +1,6 @@
 use std::collections::{HashMap, VecDeque};
 
-use crate::{Part, Puzzle, PuzzleError};
+use crate::{register_solver, Answer, PuzzleError, Solution};
 
 struct SlidingWindow {
     size: usize,
@@ -35,26 +35,26 @@ impl SlidingWindow {
     }
 }
 
-fn puzzle_one(input: Box<dyn Iterator<Item = String>>) -> Result<String, PuzzleError> {
+fn puzzle_one(input: &[String]) -> Result<Answer, PuzzleError> {
     for line in input {
         let mut window = SlidingWindow::new(4);
         for (index, letter) in line.chars().enumerate() {
             window.push_char(letter);
             if window.all_unique() {
-                return Ok((index + 1).to_string());
+                return Ok((index + 1).into());
             }
         }
     }
     Err(PuzzleError::NoSolutionFound)
 }
 
-fn puzzle_two(input: Box<dyn Iterator<Item = String>>) -> Result<String, PuzzleError> {
+fn puzzle_two(input: &[String]) -> Result<Answer, PuzzleError> {
     for line in input {
         let mut window = SlidingWindow::new(14);
         for (index, letter) in line.chars().enumerate() {
             window.push_char(letter);
             if window.all_unique() {
-                return Ok((index + 1).to_string());
+                return Ok((index + 1).into());
             }
         }
     }
@@ -63,22 +63,20 @@ fn puzzle_two(input: Box<dyn Iterator<Item = String>>) -> Result<String, PuzzleE
 
 pub struct Solver;
 
-impl Puzzle for Solver {
-    fn new() -> Box<Self>
-    where
-        Self: Sized,
-    {
-        Box::new(Self)
+register_solver!(2022, 6, Solver);
+
+impl Solution for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        Ok(input.collect())
     }
 
-    fn solve(
-        &self,
-        input: Box<dyn Iterator<Item = String>>,
-        part: Part,
-    ) -> Result<String, PuzzleError> {
-        match part {
-            Part::One => puzzle_one(input),
-            Part::Two => puzzle_two(input),
-        }
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        puzzle_two(input)
     }
 }
@@ -0,0 +1,555 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::{register_solver, Answer, InputLine, Part, PuzzleError, Solution, Token};
+
+const DAY: u8 = 7;
+
+/// The index of a directory in its `FileSystem`.
+pub type DirectoryId = usize;
+
+#[derive(Debug, Clone, Default)]
+struct Directory {
+    name: String,
+    parent: Option<DirectoryId>,
+    /// Sorted by name, as `tree` lists them
+    children: BTreeMap<String, DirectoryId>,
+    files: BTreeMap<String, usize>,
+}
+
+/// A tree of named directories and files, rooted at `/`.
+///
+/// Directories are only ever added, and always after their parent, so a
+/// parent's id is lower than any of its children's.
+#[derive(Debug, Clone)]
+pub struct FileSystem {
+    directories: Vec<Directory>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileSystem {
+    pub const ROOT: DirectoryId = 0;
+
+    pub fn new() -> Self {
+        Self {
+            directories: vec![Directory::default()],
+        }
+    }
+
+    pub fn parent(&self, dir: DirectoryId) -> Option<DirectoryId> {
+        self.directories[dir].parent
+    }
+
+    /// The child of `dir` called `name`, added if it isn't known yet.
+    pub fn child(&mut self, dir: DirectoryId, name: &str) -> DirectoryId {
+        if let Some(&child) = self.directories[dir].children.get(name) {
+            return child;
+        }
+        let child = self.directories.len();
+        self.directories.push(Directory {
+            name: name.to_string(),
+            parent: Some(dir),
+            ..Directory::default()
+        });
+        self.directories[dir]
+            .children
+            .insert(name.to_string(), child);
+        child
+    }
+
+    /// Records a file in `dir`. Listing the same file again replaces it
+    /// rather than counting it twice.
+    pub fn add_file(&mut self, dir: DirectoryId, name: &str, size: usize) {
+        self.directories[dir].files.insert(name.to_string(), size);
+    }
+
+    /// The child of `dir` called `name`, if it's known.
+    pub fn subdirectory(&self, dir: DirectoryId, name: &str) -> Option<DirectoryId> {
+        self.directories[dir].children.get(name).copied()
+    }
+
+    pub fn has_child(&self, dir: DirectoryId, name: &str) -> bool {
+        self.directories[dir].children.contains_key(name)
+    }
+
+    pub fn has_file(&self, dir: DirectoryId, name: &str) -> bool {
+        self.directories[dir].files.contains_key(name)
+    }
+
+    /// The directory at an absolute path such as `/a/e`.
+    pub fn find(&self, path: &str) -> Option<DirectoryId> {
+        let mut dir = Self::ROOT;
+        for name in path.strip_prefix('/')?.split('/') {
+            if !name.is_empty() {
+                dir = *self.directories[dir].children.get(name)?;
+            }
+        }
+        Some(dir)
+    }
+
+    /// The absolute path of `dir`, e.g. `/a/e`.
+    pub fn path(&self, dir: DirectoryId) -> String {
+        let mut names = vec![];
+        let mut current = dir;
+        while let Some(parent) = self.directories[current].parent {
+            names.push(self.directories[current].name.as_str());
+            current = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// The total size of the files in every directory, including those in
+    /// its subdirectories, indexed by id.
+    pub fn sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = self
+            .directories
+            .iter()
+            .map(|dir| dir.files.values().sum())
+            .collect();
+        // Children come after their parents, so each directory is complete
+        // by the time it's added to its parent
+        for dir in (1..self.directories.len()).rev() {
+            if let Some(parent) = self.directories[dir].parent {
+                sizes[parent] += sizes[dir];
+            }
+        }
+        sizes
+    }
+
+    /// The total size of the directory at `path`.
+    pub fn size(&self, path: &str) -> Option<usize> {
+        self.find(path).map(|dir| self.sizes()[dir])
+    }
+
+    /// The path and size of every directory, starting with the root.
+    pub fn directory_sizes(&self) -> impl Iterator<Item = (String, usize)> + '_ {
+        self.sizes()
+            .into_iter()
+            .enumerate()
+            .map(|(dir, size)| (self.path(dir), size))
+    }
+
+    /// The paths and sizes of every directory of at most `limit` bytes.
+    pub fn directories_at_most(&self, limit: usize) -> Vec<(String, usize)> {
+        self.directory_sizes()
+            .filter(|&(_, size)| size <= limit)
+            .collect()
+    }
+
+    /// The path and size of the smallest directory that would free at
+    /// least `space` bytes if it were deleted.
+    pub fn smallest_freeing(&self, space: usize) -> Option<(String, usize)> {
+        self.sizes()
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, size)| size >= space)
+            .min_by_key(|&(_, size)| size)
+            .map(|(dir, size)| (self.path(dir), size))
+    }
+
+    fn write_tree(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        sizes: &[usize],
+        dir: DirectoryId,
+        prefix: &str,
+    ) -> fmt::Result {
+        let directory = &self.directories[dir];
+        let entries = directory.children.len() + directory.files.len();
+        let children = directory
+            .children
+            .iter()
+            .map(|(name, &child)| (name, Some(child), sizes[child]));
+        let files = directory
+            .files
+            .iter()
+            .map(|(name, &size)| (name, None, size));
+        for (index, (name, child, size)) in children.chain(files).enumerate() {
+            let last = index + 1 == entries;
+            let branch = if last { "└── " } else { "├── " };
+            match child {
+                Some(child) => {
+                    writeln!(f, "{prefix}{branch}{name} (dir, size={size})")?;
+                    let indent = if last { "    " } else { "│   " };
+                    self.write_tree(f, sizes, child, &format!("{prefix}{indent}"))?;
+                }
+                None => writeln!(f, "{prefix}{branch}{name} (file, size={size})")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Draws the tree the way `tree` does, with every directory's total size.
+impl fmt::Display for FileSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sizes = self.sizes();
+        writeln!(f, "/ (dir, size={})", sizes[Self::ROOT])?;
+        self.write_tree(f, &sizes, Self::ROOT, "")
+    }
+}
+
+/// Rebuilds a `FileSystem` from a terminal session of `cd` and `ls` commands.
+pub struct TerminalParser {
+    filesystem: FileSystem,
+    working_dir: DirectoryId,
+    /// Whether the last command was an `ls`, whose output may follow
+    listing: bool,
+}
+
+impl Default for TerminalParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TerminalParser {
+    /// Follows a path, which may be absolute and have several parts, into
+    /// directories that `ls` has listed. Going up from the root stays at
+    /// the root, as it does in a shell.
+    fn change_directory(&mut self, line: &InputLine, path: Token) -> Result<(), PuzzleError> {
+        if path.text().starts_with('/') {
+            self.working_dir = FileSystem::ROOT;
+        }
+        for name in path.split("/") {
+            match name.text() {
+                "" | "." => (),
+                ".." => {
+                    if let Some(parent) = self.filesystem.parent(self.working_dir) {
+                        self.working_dir = parent;
+                    }
+                }
+                text => {
+                    if self.filesystem.has_file(self.working_dir, text) {
+                        return Err(line.error_at(name, "a directory, not a file"));
+                    }
+                    self.working_dir = self
+                        .filesystem
+                        .subdirectory(self.working_dir, text)
+                        .ok_or_else(|| line.error_at(name, "a directory listed by ls"))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_command_line(&mut self, line: &InputLine, command: Token) -> Result<(), PuzzleError> {
+        // Everything after `cd ` is the path, so names may contain spaces
+        let (name, argument) = match command.split_once(" ") {
+            Some((name, argument)) => (name, Some(argument)),
+            None => (command, None),
+        };
+        match name.text() {
+            "cd" => {
+                let path = argument.filter(|argument| !argument.text().is_empty());
+                let Some(path) = path else {
+                    return Err(line.error_at_end("a directory name"));
+                };
+                self.change_directory(line, path)?;
+                self.listing = false;
+            }
+            "ls" => self.listing = true,
+            "" => return Err(line.error_at_end("a cd or ls command")),
+            _ => return Err(line.error_at(name, "a cd or ls command")),
+        }
+        Ok(())
+    }
+
+    fn parse_listing_line(&mut self, line: &InputLine) -> Result<(), PuzzleError> {
+        if !self.listing {
+            return Err(line.error_at(line.token(), "a command, since the last command wasn't ls"));
+        }
+        let (kind, name) = line.split_once(" ", "a file size or dir followed by a name")?;
+        if matches!(name.text(), "" | "." | "..") || name.text().contains('/') {
+            return Err(line.error_at(name, "a file or directory name"));
+        }
+        if kind.text() == "dir" {
+            if self.filesystem.has_file(self.working_dir, name.text()) {
+                return Err(line.error_at(name, "a name that isn't already a file"));
+            }
+            self.filesystem.child(self.working_dir, name.text());
+        } else {
+            let file_size: usize = line.parse(kind, "a file size or dir")?;
+            if self.filesystem.has_child(self.working_dir, name.text()) {
+                return Err(line.error_at(name, "a name that isn't already a directory"));
+            }
+            self.filesystem
+                .add_file(self.working_dir, name.text(), file_size);
+        }
+        Ok(())
+    }
+
+    pub fn new() -> Self {
+        Self {
+            filesystem: FileSystem::new(),
+            working_dir: FileSystem::ROOT,
+            listing: false,
+        }
+    }
+
+    pub fn parse(&mut self, line: &InputLine) -> Result<(), PuzzleError> {
+        if let Some(command) = line.token().strip_prefix("$ ") {
+            self.parse_command_line(line, command)
+        } else {
+            self.parse_listing_line(line)
+        }
+    }
+
+    pub fn filesystem(&self) -> &FileSystem {
+        &self.filesystem
+    }
+
+    pub fn into_filesystem(self) -> FileSystem {
+        self.filesystem
+    }
+}
+
+fn parse_input(input: Box<dyn Iterator<Item = String>>) -> Result<FileSystem, PuzzleError> {
+    let mut parser = TerminalParser::new();
+    for (index, text) in input.enumerate() {
+        parser.parse(&InputLine::new(DAY, index, &text))?;
+    }
+    Ok(parser.into_filesystem())
+}
+
+fn puzzle_one(filesystem: &FileSystem) -> Result<Answer, PuzzleError> {
+    let sum: usize = filesystem
+        .directories_at_most(100000)
+        .into_iter()
+        .map(|(_, size)| size)
+        .sum();
+    Ok(sum.into())
+}
+
+fn puzzle_two(filesystem: &FileSystem) -> Result<Answer, PuzzleError> {
+    const TOTAL_SPACE: usize = 70000000;
+    const REQUIRED_SPACE: usize = 30000000;
+    let used_space = filesystem.sizes()[FileSystem::ROOT];
+    let current_free_space =
+        TOTAL_SPACE
+            .checked_sub(used_space)
+            .ok_or_else(|| PuzzleError::DataConsistencyError {
+                day: DAY,
+                part: Part::Two,
+                expected: format!("at most {TOTAL_SPACE} bytes in use"),
+                found: format!("{used_space} bytes"),
+            })?;
+    if current_free_space >= REQUIRED_SPACE {
+        return Err(PuzzleError::NoSolutionFound);
+    }
+    let (_, size) = filesystem
+        .smallest_freeing(REQUIRED_SPACE - current_free_space)
+        .ok_or(PuzzleError::NoSolutionFound)?;
+    Ok(size.into())
+}
+
+pub struct Solver;
+
+register_solver!(2022, 7, Solver);
+
+impl Solution for Solver {
+    type Input = FileSystem;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        puzzle_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+
+    const EXAMPLE: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+    fn get_test_input() -> impl BufRead {
+        BufReader::new(EXAMPLE.as_bytes())
+    }
+
+    fn parse_text(text: &str) -> Result<FileSystem, PuzzleError> {
+        parse_input(Box::new(
+            text.lines()
+                .map(|line| line.to_string())
+                .collect::<Vec<_>>()
+                .into_iter(),
+        ))
+    }
+
+    #[test]
+    fn test_puzzle_one() {
+        let input = get_test_input();
+        let filesystem = parse_input(Box::new(input.lines().map(|line| line.unwrap()))).unwrap();
+        let res = puzzle_one(&filesystem).unwrap();
+        assert_eq!(res, Answer::from(95437usize));
+    }
+
+    #[test]
+    fn test_puzzle_two() {
+        let input = get_test_input();
+        let filesystem = parse_input(Box::new(input.lines().map(|line| line.unwrap()))).unwrap();
+        let res = puzzle_two(&filesystem).unwrap();
+        assert_eq!(res, Answer::from(24933642usize));
+    }
+
+    #[test]
+    fn test_queries() {
+        let filesystem = parse_text(EXAMPLE).unwrap();
+        assert_eq!(filesystem.size("/a/e"), Some(584));
+        assert_eq!(filesystem.size("/a"), Some(94853));
+        assert_eq!(filesystem.size("/d"), Some(24933642));
+        assert_eq!(filesystem.size("/"), Some(48381165));
+        assert_eq!(filesystem.size("/b.txt"), None);
+        let mut sizes: Vec<(String, usize)> = filesystem.directory_sizes().collect();
+        sizes.sort();
+        assert_eq!(
+            sizes,
+            [
+                ("/", 48381165),
+                ("/a", 94853),
+                ("/a/e", 584),
+                ("/d", 24933642)
+            ]
+            .map(|(path, size)| (path.to_string(), size))
+        );
+        assert_eq!(
+            filesystem.directories_at_most(100000),
+            vec![("/a".to_string(), 94853), ("/a/e".to_string(), 584)]
+        );
+        assert_eq!(
+            filesystem.smallest_freeing(8381165),
+            Some(("/d".to_string(), 24933642))
+        );
+    }
+
+    #[test]
+    fn test_revisited_directories_are_counted_once() {
+        let session = format!(
+            "{EXAMPLE}
+$ ls
+4060174 j
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+$ cd a/e
+$ ls
+584 i
+$ cd /a
+$ cd ../d/../a
+$ ls
+29116 f"
+        );
+        let filesystem = parse_text(&session).unwrap();
+        assert_eq!(filesystem.sizes(), parse_text(EXAMPLE).unwrap().sizes());
+        assert_eq!(filesystem.size("/a/e"), Some(584));
+    }
+
+    #[test]
+    fn test_cd_above_root_stays_at_root() {
+        let filesystem = parse_text("$ cd /\n$ cd ..\n$ ls\n10 a").unwrap();
+        assert_eq!(filesystem.size("/"), Some(10));
+    }
+
+    #[test]
+    fn test_cd_only_enters_listed_directories() {
+        let error = |text| match parse_text(text).unwrap_err() {
+            PuzzleError::ParseError {
+                line_number,
+                column,
+                expected,
+                ..
+            } => (line_number, column, expected),
+            err => panic!("Expected a parse error, found {err}"),
+        };
+        assert_eq!(
+            error("$ cd /\n$ ls\n10 a\n$ cd a"),
+            (4, 6, "a directory, not a file".to_string())
+        );
+        assert_eq!(
+            error("$ cd /\n$ ls\ndir a\n$ cd a/b"),
+            (4, 8, "a directory listed by ls".to_string())
+        );
+        assert_eq!(
+            error("$ cd /\n$ cd a"),
+            (2, 6, "a directory listed by ls".to_string())
+        );
+
+        let filesystem =
+            parse_text("$ cd /\n$ ls\ndir a\n$ cd /a\n$ ls\n10 b\n$ cd ../a/.").unwrap();
+        assert_eq!(filesystem.size("/a"), Some(10));
+    }
+
+    #[test]
+    fn test_names_with_spaces() {
+        let filesystem =
+            parse_text("$ cd /\n$ ls\ndir my files\n$ cd my files\n$ ls\n10 a b.txt").unwrap();
+        assert_eq!(filesystem.size("/my files"), Some(10));
+    }
+
+    #[test]
+    fn test_output_must_follow_ls() {
+        let err = parse_text("$ cd /\n10 a").unwrap_err();
+        assert_eq!(err.kind(), "parse");
+        let err = parse_text("$ ls\ndir a\n10 a").unwrap_err();
+        assert_eq!(err.kind(), "parse");
+    }
+
+    #[test]
+    fn test_tree() {
+        let filesystem = parse_text(EXAMPLE).unwrap();
+        assert_eq!(
+            filesystem.to_string(),
+            "/ (dir, size=48381165)
+├── a (dir, size=94853)
+│   ├── e (dir, size=584)
+│   │   └── i (file, size=584)
+│   ├── f (file, size=29116)
+│   ├── g (file, size=2557)
+│   └── h.lst (file, size=62596)
+├── d (dir, size=24933642)
+│   ├── d.ext (file, size=5626152)
+│   ├── d.log (file, size=8033020)
+│   ├── j (file, size=4060174)
+│   └── k (file, size=7214296)
+├── b.txt (file, size=14848514)
+└── c.dat (file, size=8504156)
+"
+        );
+    }
+}
@@ -1,30 +1,49 @@
 use std::collections::HashSet;
 
-use crate::{Part, Puzzle, PuzzleError};
+use crate::{register_solver, Answer, InputLine, PuzzleError, Solution};
+
+const DAY: u8 = 8;
 
 pub struct Forest {
     tree_grid: Vec<Vec<u8>>,
 }
 
 impl Forest {
-    pub fn from_input(input: Box<dyn Iterator<Item = String>>) -> Self {
+    pub fn from_input(input: Box<dyn Iterator<Item = String>>) -> Result<Self, PuzzleError> {
         let mut tree_grid = vec![];
 
-        for line in input {
-            let mut row = Vec::with_capacity(line.len());
-            for number in line.chars() {
-                let height = number.to_digit(10).unwrap() as u8;
+        for (index, text) in input.enumerate() {
+            let line = InputLine::new(DAY, index, &text);
+            let mut row = Vec::with_capacity(text.len());
+            for (column, number) in text.chars().enumerate() {
+                let height = number
+                    .to_digit(10)
+                    .ok_or_else(|| line.error(column + 1, "a tree height", number))?
+                    as u8;
                 row.push(height);
             }
+            // Every row has to be as wide as the first for the grid to be walkable
+            if let Some(first_row) = tree_grid.first().map(Vec::len) {
+                if row.len() != first_row {
+                    let column = row.len().min(first_row) + 1;
+                    let found = format!("{} trees", row.len());
+                    return Err(line.error(column, format!("{first_row} trees"), found));
+                }
+            }
             tree_grid.push(row);
         }
 
-        Self { tree_grid }
+        Ok(Self { tree_grid })
     }
 }
 
-fn puzzle_one(input: Box<dyn Iterator<Item = String>>) -> Result<String, PuzzleError> {
-    let forest = Forest::from_input(input);
+fn puzzle_one(forest: &Forest) -> Result<Answer, PuzzleError> {
+    let rows = forest.tree_grid.len();
+    let columns = forest.tree_grid.first().map_or(0, Vec::len);
+    // Without an interior, every tree is on the edge
+    if rows < 3 || columns < 3 {
+        return Ok((rows * columns).into());
+    }
     let mut seen_trees = HashSet::new();
     let mut visible_trees = forest.tree_grid.len() * 2 + forest.tree_grid[0].len() * 2 - 4;
 
@@ -90,11 +109,10 @@ fn puzzle_one(input: Box<dyn Iterator<Item = String>>) -> Result<String, PuzzleE
             }
         }
     }
-    Ok(visible_trees.to_string())
+    Ok(visible_trees.into())
 }
 
-fn puzzle_two(input: Box<dyn Iterator<Item = String>>) -> Result<String, PuzzleError> {
-    let forest = Forest::from_input(input);
+fn puzzle_two(forest: &Forest) -> Result<Answer, PuzzleError> {
     let mut scenic_score = 0;
     for (row_idx, row) in forest.tree_grid.iter().enumerate() {
         for (col_idx, &tree) in row.iter().enumerate() {
@@ -138,28 +156,26 @@ fn puzzle_two(input: Box<dyn Iterator<Item = String>>) -> Result<String, PuzzleE
             scenic_score = std::cmp::max(tree_scenic_score, scenic_score);
         }
     }
-    Ok(scenic_score.to_string())
+    Ok(scenic_score.into())
 }
 
 pub struct Solver;
 
-impl Puzzle for Solver {
-    fn new() -> Box<Self>
-    where
-        Self: Sized,
-    {
-        Box::new(Self)
+register_solver!(2022, 8, Solver);
+
+impl Solution for Solver {
+    type Input = Forest;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        Forest::from_input(input)
     }
 
-    fn solve(
-        &self,
-        input: Box<dyn Iterator<Item = String>>,
-        part: Part,
-    ) -> Result<String, PuzzleError> {
-        match part {
-            Part::One => puzzle_one(input),
-            Part::Two => puzzle_two(input),
-        }
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        puzzle_two(input)
     }
 }
 
@@ -182,14 +198,32 @@ mod tests {
     #[test]
     fn test_puzzle_one() {
         let input = get_test_input();
-        let ans = puzzle_one(input).unwrap();
-        assert_eq!(ans, "21");
+        let ans = puzzle_one(&Forest::from_input(input).unwrap()).unwrap();
+        assert_eq!(ans, Answer::from(21));
     }
 
     #[test]
     fn test_puzzle_two() {
         let input = get_test_input();
-        let ans = puzzle_two(input).unwrap();
-        assert_eq!(ans, "8");
+        let ans = puzzle_two(&Forest::from_input(input).unwrap()).unwrap();
+        assert_eq!(ans, Answer::from(8));
+    }
+
+    #[test]
+    fn test_from_input_malformed() {
+        let input = Box::new(["303", "2a5"].into_iter().map(String::from));
+        assert!(matches!(
+            Forest::from_input(input),
+            Err(PuzzleError::ParseError {
+                line_number: 2,
+                column: 2,
+                ..
+            })
+        ));
+        let input = Box::new(["303", "25"].into_iter().map(String::from));
+        assert!(matches!(
+            Forest::from_input(input),
+            Err(PuzzleError::ParseError { column: 3, .. })
+        ));
     }
 }
@@ -0,0 +1,477 @@
+use std::collections::HashSet;
+
+use crate::{
+    register_solver, Answer, Frame, Granularity, InputLine, Observer, Part, PuzzleError, Solution,
+    Token,
+};
+
+const DAY: u8 = 9;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Right,
+    Left,
+    Up,
+    Down,
+}
+
+impl Direction {
+    /// Parses `token`, a piece of `line`, as one of R, L, U or D.
+    fn parse(line: &InputLine, token: Token) -> Result<Self, PuzzleError> {
+        let direction = match token.text() {
+            "R" => Self::Right,
+            "L" => Self::Left,
+            "U" => Self::Up,
+            "D" => Self::Down,
+            _ => return Err(line.error_at(token, "one of R, L, U or D")),
+        };
+        Ok(direction)
+    }
+
+    fn letter(self) -> char {
+        match self {
+            Self::Right => 'R',
+            Self::Left => 'L',
+            Self::Up => 'U',
+            Self::Down => 'D',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+struct Coordinate {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Coordinate {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn add_x(mut self, x: i32) -> Self {
+        self.x += x;
+        self
+    }
+
+    pub fn add_y(mut self, y: i32) -> Self {
+        self.y += y;
+        self
+    }
+
+    pub fn is_adjacent_to(&self, other: &Self) -> bool {
+        if (self.x - other.x).abs() <= 1 && (self.y - other.y).abs() <= 1 {
+            return true;
+        }
+        false
+    }
+}
+
+/// A rope of knots, each following the one before it. Tracks every position
+/// one chosen knot has visited.
+struct RopeTracker<'a> {
+    seen_positions: HashSet<Coordinate>,
+    knots: Vec<Coordinate>,
+    tracked_knot: usize,
+    observer: Option<&'a mut dyn Observer>,
+}
+
+impl<'a> RopeTracker<'a> {
+    /// Moves `knot` one step towards `leader` if they're no longer adjacent,
+    /// diagonally if they're in different rows and columns.
+    fn follow(knot: Coordinate, leader: Coordinate) -> Coordinate {
+        if leader.is_adjacent_to(&knot) {
+            return knot;
+        }
+        knot.add_x((leader.x - knot.x).signum())
+            .add_y((leader.y - knot.y).signum())
+    }
+
+    fn update_positions(&mut self, direction: Direction) {
+        let head_pos = self.knots[0];
+        self.knots[0] = match direction {
+            Direction::Up => head_pos.add_y(1),
+            Direction::Down => head_pos.add_y(-1),
+            Direction::Left => head_pos.add_x(-1),
+            Direction::Right => head_pos.add_x(1),
+        };
+        for index in 1..self.knots.len() {
+            self.knots[index] = Self::follow(self.knots[index], self.knots[index - 1]);
+        }
+        self.seen_positions.insert(self.knots[self.tracked_knot]);
+    }
+
+    /// A rope of `knot_count` knots starting on top of each other, tracking
+    /// the tail.
+    pub fn new(knot_count: usize) -> Self {
+        Self::tracking(knot_count, knot_count - 1)
+    }
+
+    /// A rope of `knot_count` knots tracking the knot at `tracked_knot`,
+    /// where the head is knot 0.
+    pub fn tracking(knot_count: usize, tracked_knot: usize) -> Self {
+        assert!(
+            tracked_knot < knot_count,
+            "Can't track knot {tracked_knot} of a rope with {knot_count} knots"
+        );
+        Self {
+            seen_positions: HashSet::from([Coordinate::default()]),
+            knots: vec![Coordinate::default(); knot_count],
+            tracked_knot,
+            observer: None,
+        }
+    }
+
+    /// Passes `observer` a drawing of the rope as it starts and then after
+    /// every step or instruction.
+    pub fn with_observer(mut self, observer: &'a mut dyn Observer) -> Self {
+        self.observer = Some(observer);
+        self.snapshot(None, "Initial State");
+        self
+    }
+
+    /// Draws the rope if the observer wants a frame at this granularity, or
+    /// at any granularity when `granularity` is `None`.
+    fn snapshot(&mut self, granularity: Option<Granularity>, title: &str) {
+        let wanted = match (&self.observer, granularity) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(observer), Some(granularity)) => observer.granularity() == granularity,
+        };
+        if wanted {
+            let frame = Frame::grid(title, self.render());
+            if let Some(observer) = &mut self.observer {
+                observer.frame(frame);
+            }
+        }
+    }
+
+    pub fn apply_instruction(&mut self, direction: Direction, steps: i32) {
+        let instruction = format!("{} {steps}", direction.letter());
+        for step in 1..=steps {
+            self.update_positions(direction);
+            self.snapshot(
+                Some(Granularity::Step),
+                &format!("{instruction} (step {step} of {steps})"),
+            );
+        }
+        self.snapshot(Some(Granularity::Instruction), &instruction);
+    }
+
+    pub fn unique_positions(&self) -> usize {
+        self.seen_positions.len()
+    }
+
+    /// The symbol the puzzle draws for a knot: H for the head, T for the
+    /// tail of a two knot rope, and otherwise the knot's number.
+    fn knot_symbol(&self, index: usize) -> char {
+        match index {
+            0 => 'H',
+            _ if self.knots.len() == 2 => 'T',
+            _ => char::from_digit(index as u32 % 36, 36).expect("Knot numbers are taken mod 36"),
+        }
+    }
+
+    /// Draws the rope the way the puzzle does, with `s` for the start and `#`
+    /// for positions the tracked knot has visited. Knots nearer the head are
+    /// drawn over those behind them. The drawing is cropped to the visited
+    /// positions and the rope, with up at the top.
+    pub fn render(&self) -> Vec<String> {
+        let origin = Coordinate::default();
+        let cells = || {
+            self.seen_positions
+                .iter()
+                .chain(&self.knots)
+                .chain([&origin])
+        };
+        let (min_x, max_x) = (cells().map(|c| c.x).min(), cells().map(|c| c.x).max());
+        let (min_y, max_y) = (cells().map(|c| c.y).min(), cells().map(|c| c.y).max());
+        let (Some(min_x), Some(max_x), Some(min_y), Some(max_y)) = (min_x, max_x, min_y, max_y)
+        else {
+            unreachable!("The origin is always drawn");
+        };
+
+        let mut rows = vec![];
+        for y in (min_y..=max_y).rev() {
+            let row: String = (min_x..=max_x)
+                .map(|x| {
+                    let cell = Coordinate::new(x, y);
+                    if let Some(index) = self.knots.iter().position(|&knot| knot == cell) {
+                        self.knot_symbol(index)
+                    } else if cell == origin {
+                        's'
+                    } else if self.seen_positions.contains(&cell) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            rows.push(row);
+        }
+        rows
+    }
+}
+
+/// Counts the positions visited by the tail of a rope with `knot_count` knots.
+fn count_tail_positions(instructions: &[(Direction, i32)], knot_count: usize) -> usize {
+    simulate(RopeTracker::new(knot_count), instructions)
+}
+
+fn simulate(mut rope_tracker: RopeTracker, instructions: &[(Direction, i32)]) -> usize {
+    for &(direction, steps) in instructions {
+        rope_tracker.apply_instruction(direction, steps);
+    }
+    rope_tracker.unique_positions()
+}
+
+/// The number of knots in each part's rope.
+fn knot_count(part: Part) -> usize {
+    match part {
+        Part::One => 2,
+        Part::Two => 10,
+    }
+}
+
+fn parse_instruction(line: &InputLine) -> Result<(Direction, i32), PuzzleError> {
+    let (direction, steps_token) = line.split_once(" ", "a direction followed by a step count")?;
+    let direction = Direction::parse(line, direction)?;
+    let steps: i32 = line.parse(steps_token, "a step count")?;
+    if steps < 0 {
+        return Err(line.error_at(steps_token, "a non-negative step count"));
+    }
+    Ok((direction, steps))
+}
+
+fn parse_input(
+    input: Box<dyn Iterator<Item = String>>,
+) -> Result<Vec<(Direction, i32)>, PuzzleError> {
+    input
+        .enumerate()
+        .map(|(index, text)| parse_instruction(&InputLine::new(DAY, index, &text)))
+        .collect()
+}
+
+fn puzzle_one(instructions: &[(Direction, i32)]) -> Result<Answer, PuzzleError> {
+    Ok(count_tail_positions(instructions, knot_count(Part::One)).into())
+}
+
+fn puzzle_two(instructions: &[(Direction, i32)]) -> Result<Answer, PuzzleError> {
+    Ok(count_tail_positions(instructions, knot_count(Part::Two)).into())
+}
+
+pub struct Solver;
+
+register_solver!(2022, 9, Solver);
+
+impl Solution for Solver {
+    type Input = Vec<(Direction, i32)>;
+
+    fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        puzzle_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, PuzzleError> {
+        puzzle_two(input)
+    }
+
+    fn observe(
+        input: &Self::Input,
+        part: Part,
+        observer: &mut dyn Observer,
+    ) -> Option<Result<Answer, PuzzleError>> {
+        let rope_tracker = RopeTracker::new(knot_count(part)).with_observer(observer);
+        let tail_positions = simulate(rope_tracker, input);
+        observer.frame(Frame::stats(
+            "Done",
+            [("positions visited by the tail", tail_positions)],
+        ));
+        Some(Ok(tail_positions.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader};
+
+    use super::*;
+    use crate::observe::FrameLog;
+
+    fn get_test_input() -> Box<dyn Iterator<Item = String>> {
+        let test_data = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+        let reader = BufReader::new(test_data.as_bytes());
+        Box::new(reader.lines().map(|line| line.unwrap()))
+    }
+
+    fn get_larger_test_input() -> Box<dyn Iterator<Item = String>> {
+        let test_data = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+        let reader = BufReader::new(test_data.as_bytes());
+        Box::new(reader.lines().map(|line| line.unwrap()))
+    }
+
+    #[test]
+    fn test_coordinate_add_y() {
+        let coord_1 = Coordinate::new(5, 10);
+        let coord_2 = coord_1.add_y(1);
+        assert_eq!(coord_1.x, coord_2.x);
+        assert_eq!(coord_1.y, coord_2.y - 1);
+    }
+
+    #[test]
+    fn test_coordinate_add_x() {
+        let coord_1 = Coordinate::new(5, 10);
+        let coord_2 = coord_1.add_x(1);
+        assert_eq!(coord_1.x, coord_2.x - 1);
+        assert_eq!(coord_1.y, coord_2.y);
+    }
+
+    #[test]
+    fn test_coordinate_is_adjacent_to() {
+        let coord_1 = Coordinate::new(5, 10);
+        let coord_2 = Coordinate::new(5, 10);
+        assert!(coord_1.is_adjacent_to(&coord_2));
+
+        let coord_2 = Coordinate::new(5, 11);
+        assert!(coord_1.is_adjacent_to(&coord_2));
+
+        let coord_2 = Coordinate::new(6, 11);
+        assert!(coord_1.is_adjacent_to(&coord_2));
+
+        let coord_2 = Coordinate::new(6, 10);
+        assert!(coord_1.is_adjacent_to(&coord_2));
+
+        let coord_2 = Coordinate::new(6, 9);
+        assert!(coord_1.is_adjacent_to(&coord_2));
+
+        let coord_2 = Coordinate::new(5, 9);
+        assert!(coord_1.is_adjacent_to(&coord_2));
+
+        let coord_2 = Coordinate::new(4, 9);
+        assert!(coord_1.is_adjacent_to(&coord_2));
+
+        let coord_2 = Coordinate::new(4, 10);
+        assert!(coord_1.is_adjacent_to(&coord_2));
+
+        let coord_2 = Coordinate::new(4, 11);
+        assert!(coord_1.is_adjacent_to(&coord_2));
+
+        let coord_2 = Coordinate::new(4, 8);
+        assert!(!coord_1.is_adjacent_to(&coord_2));
+    }
+
+    #[test]
+    fn test_puzzle_one() {
+        let input = get_test_input();
+        let ans = puzzle_one(&parse_input(input).unwrap()).unwrap();
+        assert_eq!(ans, Answer::from(13));
+    }
+
+    #[test]
+    fn test_puzzle_two() {
+        let input = get_test_input();
+        let ans = puzzle_two(&parse_input(input).unwrap()).unwrap();
+        assert_eq!(ans, Answer::from(1));
+
+        let input = get_larger_test_input();
+        let ans = puzzle_two(&parse_input(input).unwrap()).unwrap();
+        assert_eq!(ans, Answer::from(36));
+    }
+
+    #[test]
+    fn test_registered_solver() {
+        let solver = crate::registry::get_solver(2022, 9).unwrap();
+        assert_eq!(
+            solver.solve(get_test_input(), Part::One).unwrap(),
+            Answer::from(13)
+        );
+        assert_eq!(
+            solver.solve(get_larger_test_input(), Part::Two).unwrap(),
+            Answer::from(36)
+        );
+    }
+
+    #[test]
+    fn test_rope_tracker_tracks_chosen_knot() {
+        // The knot behind the head moves the same however long the rope is
+        let instructions = parse_input(get_test_input()).unwrap();
+        let mut rope_tracker = RopeTracker::tracking(10, 1);
+        for &(direction, steps) in &instructions {
+            rope_tracker.apply_instruction(direction, steps);
+        }
+        assert_eq!(rope_tracker.unique_positions(), 13);
+    }
+
+    #[test]
+    fn test_rope_tracker_render() {
+        let mut log = FrameLog::new(Granularity::Step);
+        let mut rope_tracker = RopeTracker::new(2).with_observer(&mut log);
+        rope_tracker.apply_instruction(Direction::Right, 4);
+        rope_tracker.apply_instruction(Direction::Up, 2);
+        drop(rope_tracker);
+        let frames: Vec<String> = log.frames.iter().map(Frame::render).collect();
+        assert_eq!(frames.len(), 7);
+        assert_eq!(frames[0], "== Initial State ==\n\nH");
+        assert_eq!(frames[4], "== R 4 (step 4 of 4) ==\n\ns##TH");
+        assert_eq!(frames[6], "== U 2 (step 2 of 2) ==\n\n....H\n....T\ns###.");
+
+        let mut log = FrameLog::new(Granularity::Instruction);
+        let rope_tracker = RopeTracker::new(10).with_observer(&mut log);
+        let instructions = parse_input(get_test_input()).unwrap();
+        assert_eq!(simulate(rope_tracker, &instructions[..2]), 1);
+        assert_eq!(log.frames[1], Frame::grid("R 4", vec!["4321H".to_string()]));
+        assert_eq!(
+            log.frames[2].render(),
+            "== U 4 ==\n\n....H\n....1\n..432\n.5...\n6....",
+        );
+    }
+
+    #[test]
+    fn test_observe() {
+        let instructions = parse_input(get_test_input()).unwrap();
+        let mut log = FrameLog::new(Granularity::Instruction);
+        let answer = Solver::observe(&instructions, Part::One, &mut log).unwrap();
+        assert_eq!(answer.unwrap(), Answer::from(13));
+        // The initial state, one frame per instruction and the final stats
+        assert_eq!(log.frames.len(), 10);
+        assert_eq!(
+            log.frames[9],
+            Frame::stats("Done", [("positions visited by the tail", 13)])
+        );
+    }
+
+    #[test]
+    fn test_parse_input_malformed() {
+        let input = Box::new(["R 4", "X 2"].into_iter().map(String::from));
+        assert!(matches!(
+            parse_input(input),
+            Err(PuzzleError::ParseError {
+                line_number: 2,
+                column: 1,
+                ..
+            })
+        ));
+        let input = Box::new(["R"].into_iter().map(String::from));
+        assert!(matches!(
+            parse_input(input),
+            Err(PuzzleError::ParseError { column: 2, .. })
+        ));
+    }
+}
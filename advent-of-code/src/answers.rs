@@ -0,0 +1,48 @@
+use std::path::Path;
+
+use aoc_puzzles::known_answers::{AnswersError, KnownAnswers, Verification, ANSWERS_FILE_NAME};
+
+use crate::runner::PartResult;
+
+/// Checks every successful result against its day's answers file.
+pub fn verify_results(data_root: &Path, results: &mut [PartResult]) -> Result<(), AnswersError> {
+    for result in results.iter_mut() {
+        if let Ok(answer) = &result.answer {
+            result.verification =
+                KnownAnswers::load(data_root, result.year, result.day)?.verify(result.part, answer);
+        }
+    }
+    Ok(())
+}
+
+/// Prints an expected/found diff for every result that disagrees with its known answer.
+pub fn print_mismatches(results: &[PartResult]) {
+    for result in results {
+        if let (Verification::Incorrect { expected }, Ok(found)) =
+            (&result.verification, &result.answer)
+        {
+            eprintln!(
+                "Day {}, part {:?} does not match {ANSWERS_FILE_NAME}:\n  - expected: {expected}\n  + found:    {found}",
+                result.day, result.part
+            );
+        }
+    }
+}
+
+/// Saves every successful result as the confirmed answer for its day and part.
+pub fn record_results(data_root: &Path, results: &[PartResult]) -> Result<(), AnswersError> {
+    for result in results {
+        let Ok(answer) = &result.answer else {
+            continue;
+        };
+        let mut known_answers = KnownAnswers::load(data_root, result.year, result.day)?;
+        if let Some(previous) = known_answers.set(result.part, answer) {
+            eprintln!(
+                "Day {}, part {:?}: replacing recorded answer {previous} with {answer}",
+                result.day, result.part
+            );
+        }
+        known_answers.save(data_root, result.year, result.day)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+
+use aoc_puzzles::{registry, Answer, Part, PuzzleError};
+
+use crate::runner::{self, InputSource};
+
+/// Summary statistics for a set of timing samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let len = sorted.len();
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+        // Nearest-rank percentile
+        let p95_rank = (len * 95).div_ceil(100);
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / len as u32,
+            p95: sorted[p95_rank - 1],
+        })
+    }
+
+    fn to_json(self) -> serde_json::Value {
+        json!({
+            "min_ns": self.min.as_nanos() as u64,
+            "median_ns": self.median.as_nanos() as u64,
+            "mean_ns": self.mean.as_nanos() as u64,
+            "p95_ns": self.p95.as_nanos() as u64,
+        })
+    }
+}
+
+pub struct BenchResult {
+    pub day: u8,
+    pub part: Part,
+    pub iterations: u32,
+    pub warmup: u32,
+    pub answer: Answer,
+    pub load: Stats,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl BenchResult {
+    pub fn print_human(&self) {
+        println!(
+            "Day {}, part {:?} ({} iterations, {} warmup), answer: {}",
+            self.day, self.part, self.iterations, self.warmup, self.answer
+        );
+        for (name, stats) in [
+            ("load", self.load),
+            ("parse", self.parse),
+            ("solve", self.solve),
+        ] {
+            println!(
+                "  {name:<5}  min {:>10.2?}  median {:>10.2?}  mean {:>10.2?}  p95 {:>10.2?}",
+                stats.min, stats.median, stats.mean, stats.p95
+            );
+        }
+    }
+
+    pub fn print_json(&self, year: u16) {
+        let output = json!({
+            "year": year,
+            "day": self.day,
+            "part": format!("{:?}", self.part),
+            "iterations": self.iterations,
+            "warmup": self.warmup,
+            "answer": self.answer,
+            "load": self.load.to_json(),
+            "parse": self.parse.to_json(),
+            "solve": self.solve.to_json(),
+        });
+        println!("{output}");
+    }
+}
+
+/// Runs one part `iterations` times after `warmup` untimed runs.
+///
+/// Reading the input file, parsing it and solving are each timed separately
+/// so that IO noise doesn't hide changes in the solver itself.
+pub fn bench_part(
+    data_root: &Path,
+    source: &InputSource,
+    year: u16,
+    day: u8,
+    part: Part,
+    iterations: u32,
+    warmup: u32,
+) -> Result<BenchResult, PuzzleError> {
+    assert!(iterations > 0, "Benchmarks require at least one iteration");
+    let solver = registry::get_solver(year, day)?;
+    let mut load_samples = Vec::with_capacity(iterations as usize);
+    let mut parse_samples = Vec::with_capacity(iterations as usize);
+    let mut solve_samples = Vec::with_capacity(iterations as usize);
+    let mut answer = None;
+
+    for iteration in 0..warmup + iterations {
+        let start = Instant::now();
+        let input: Vec<String> = runner::open_input(data_root, year, day, source)?.collect();
+        let loaded = Instant::now();
+        let parsed = solver.parse(Box::new(input.into_iter()))?;
+        let parsed_at = Instant::now();
+        answer = Some(parsed.solve(part)?);
+        let solved = Instant::now();
+
+        if iteration >= warmup {
+            load_samples.push(loaded - start);
+            parse_samples.push(parsed_at - loaded);
+            solve_samples.push(solved - parsed_at);
+        }
+    }
+
+    Ok(BenchResult {
+        day,
+        part,
+        iterations,
+        warmup,
+        answer: answer.expect("No iterations were run"),
+        load: Stats::from_samples(&load_samples).expect("No iterations were run"),
+        parse: Stats::from_samples(&parse_samples).expect("No iterations were run"),
+        solve: Stats::from_samples(&solve_samples).expect("No iterations were run"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let stats = Stats::from_samples(&millis(&[5, 1, 3, 2, 4])).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+
+        let samples: Vec<u64> = (1..=100).collect();
+        let stats = Stats::from_samples(&millis(&samples)).unwrap();
+        assert_eq!(stats.median, Duration::from_micros(50_500));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+    }
+
+    #[test]
+    fn test_stats_from_no_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+}
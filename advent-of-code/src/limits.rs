@@ -0,0 +1,239 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io::{Read, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicIsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+
+use crate::pool::Panic;
+
+/// How often a job's process is checked for having finished.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The status a job's process exits with when it goes over its memory limit.
+const MEMORY_LIMIT_EXIT_CODE: i32 = 3;
+
+/// No limit, for `MEMORY_CAP`.
+const UNLIMITED: isize = isize::MAX;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Bytes allocated less bytes freed by this process.
+static ALLOCATED: AtomicIsize = AtomicIsize::new(0);
+
+/// The most `ALLOCATED` may reach before the process exits.
+static MEMORY_CAP: AtomicIsize = AtomicIsize::new(UNLIMITED);
+
+/// Counts an allocation, exiting straight away once the process is over its
+/// cap. Unwinding out of an allocator isn't allowed and a null pointer would
+/// abort with a status that can't be told apart from other aborts, so this
+/// exits without running any more of the program.
+fn charge(size: usize) {
+    let allocated = ALLOCATED.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+    if allocated > MEMORY_CAP.load(Ordering::Relaxed) {
+        // Safety: `_exit` ends the process without touching its memory
+        unsafe { libc::_exit(MEMORY_LIMIT_EXIT_CODE) };
+    }
+}
+
+fn refund(size: usize) {
+    ALLOCATED.fetch_sub(size as isize, Ordering::Relaxed);
+}
+
+/// The system allocator, counting the bytes allocated by the process so that
+/// a job's process can be held to its memory limit.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        charge(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        charge(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if new_size > layout.size() {
+            charge(new_size - layout.size());
+        } else {
+            refund(layout.size() - new_size);
+        }
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        refund(layout.size());
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// Makes this process exit with `MEMORY_LIMIT_EXIT_CODE` as soon as it has
+/// allocated more than `limit` bytes beyond what it holds now, or lifts the
+/// limit if given `None`. Only a job's process should set a limit.
+pub fn set_memory_limit(limit: Option<usize>) {
+    let cap = limit.map_or(UNLIMITED, |limit| {
+        let limit = isize::try_from(limit).unwrap_or(UNLIMITED);
+        ALLOCATED.load(Ordering::Relaxed).saturating_add(limit)
+    });
+    MEMORY_CAP.store(cap, Ordering::Relaxed);
+}
+
+/// Limits on solving a single part.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    /// The most bytes the part may have allocated at once
+    pub memory: Option<usize>,
+}
+
+/// Why a limited job didn't return.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Interrupted {
+    Panicked(Panic),
+    TimedOut(Duration),
+    MemoryLimitExceeded(usize),
+}
+
+/// Runs `command` as a job within `limits`, writing `input` to its stdin one
+/// line at a time and reading its result as JSON from its stdout.
+///
+/// The job is expected to call `set_memory_limit` with `limits.memory`
+/// itself. A job that runs past the timeout is killed, so nothing is left
+/// running once this returns.
+pub fn run_job<R: DeserializeOwned>(
+    limits: Limits,
+    mut command: Command,
+    input: &[String],
+) -> Result<R, Interrupted> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|err| job_failed(format!("The job couldn't be started: {err}")))?;
+
+    let mut stdin = child.stdin.take().expect("The job's stdin is piped");
+    let input = input.join("\n");
+    // A job that exits early stops reading, which is reported by its status
+    let writer = thread::spawn(move || {
+        let _ = stdin.write_all(input.as_bytes());
+    });
+    let mut stdout = child.stdout.take().expect("The job's stdout is piped");
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        stdout.read_to_string(&mut output).map(|_| output)
+    });
+
+    let start = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Ok(status),
+            Ok(None) => {}
+            Err(err) => break Err(job_failed(format!("The job couldn't be waited on: {err}"))),
+        }
+        if let Some(timeout) = limits.timeout.filter(|&timeout| start.elapsed() >= timeout) {
+            let _ = child.kill();
+            let _ = child.wait();
+            break Err(Interrupted::TimedOut(timeout));
+        }
+        let wait = limits.timeout.map_or(POLL_INTERVAL, |timeout| {
+            timeout.saturating_sub(start.elapsed()).min(POLL_INTERVAL)
+        });
+        thread::sleep(wait);
+    };
+    // Both ends of the pipes are closed once the job has exited
+    let _ = writer.join();
+    let output = reader
+        .join()
+        .expect("Reading the job's output doesn't panic");
+
+    match (status?, limits.memory) {
+        (status, Some(limit)) if status.code() == Some(MEMORY_LIMIT_EXIT_CODE) => {
+            Err(Interrupted::MemoryLimitExceeded(limit))
+        }
+        (status, _) if !status.success() => Err(exited(status)),
+        _ => output
+            .ok()
+            .and_then(|output| serde_json::from_str(&output).ok())
+            .ok_or_else(|| job_failed("The job exited without a result".to_string())),
+    }
+}
+
+fn job_failed(message: String) -> Interrupted {
+    Interrupted::Panicked(Panic {
+        message,
+        location: None,
+    })
+}
+
+/// A job that crashed, such as by overflowing its stack.
+fn exited(status: ExitStatus) -> Interrupted {
+    job_failed(format!("The job's process stopped unexpectedly ({status})"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shell(script: &str) -> Command {
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+        command
+    }
+
+    #[test]
+    fn test_run_job_returns_result() {
+        let limits = Limits {
+            timeout: Some(Duration::from_secs(10)),
+            memory: Some(1 << 20),
+        };
+        let input = ["3".to_string(), "4".to_string()];
+        let lines: Vec<u32> = run_job(limits, shell("echo [$(paste -sd, -)]"), &input).unwrap();
+        assert_eq!(lines, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_run_job_kills_job_on_timeout() {
+        let limits = Limits {
+            timeout: Some(Duration::from_millis(50)),
+            memory: None,
+        };
+        let start = Instant::now();
+        let result = run_job::<u32>(limits, shell("exec sleep 10"), &[]);
+        assert_eq!(
+            result,
+            Err(Interrupted::TimedOut(Duration::from_millis(50)))
+        );
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_run_job_memory_limit() {
+        let limits = Limits {
+            timeout: None,
+            memory: Some(1 << 20),
+        };
+        let script = format!("exit {MEMORY_LIMIT_EXIT_CODE}");
+        assert_eq!(
+            run_job::<u32>(limits, shell(&script), &[]),
+            Err(Interrupted::MemoryLimitExceeded(1 << 20))
+        );
+    }
+
+    #[test]
+    fn test_run_job_reports_crashes() {
+        let result = run_job::<u32>(Limits::default(), shell("kill -SEGV $$"), &[]);
+        let Err(Interrupted::Panicked(panic)) = result else {
+            panic!("Expected the crash to be reported, found {result:?}");
+        };
+        assert!(panic.message.contains("stopped unexpectedly"));
+
+        let result = run_job::<u32>(Limits::default(), shell("echo nonsense"), &[]);
+        assert!(matches!(result, Err(Interrupted::Panicked(_))));
+    }
+}
@@ -1,17 +1,81 @@
-use std::io::BufReader;
-use std::{fs::File, io::BufRead};
+use std::fs;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::Duration;
 
 use chrono::{Datelike, Local};
-use clap::{Parser, ValueEnum};
+use clap::{Args as ClapArgs, Parser, Subcommand, ValueEnum};
 
 use aoc_puzzles::*;
 
-const DATA_FILE_PATH: &str = "./advent-of-code/aoc-puzzles/data/";
-const INPUT_FILE_NAME: &str = "input.txt";
+mod answers;
+mod bench;
+mod limits;
+mod pool;
+mod report;
+mod runner;
+mod transcript;
+mod visualize;
+mod watch;
 
-fn day_num_from_today() -> u8 {
+use limits::Limits;
+use runner::{InputSource, PartResult};
+
+/// Today's day number, if today falls within the Advent of Code event.
+fn day_num_from_today() -> Option<u8> {
     let today = Local::now();
-    today.day() as u8
+    if today.month() == 12 && today.day() <= 25 {
+        Some(today.day() as u8)
+    } else {
+        None
+    }
+}
+
+/// Parses either a single day (`5`) or an inclusive range of days (`3..7` or `3..=7`).
+fn parse_day_range(value: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse_day = |day: &str| {
+        day.trim()
+            .parse::<u8>()
+            .map_err(|_| format!("'{day}' is not a valid day"))
+    };
+    let range = match value.split_once("..") {
+        Some((start, end)) => {
+            let end = end.strip_prefix('=').unwrap_or(end);
+            parse_day(start)?..=parse_day(end)?
+        }
+        None => {
+            let day = parse_day(value)?;
+            day..=day
+        }
+    };
+    if range.is_empty() {
+        return Err(format!("'{value}' does not contain any days"));
+    }
+    Ok(range)
+}
+
+/// Parses a positive, possibly fractional, number of seconds.
+fn parse_seconds(value: &str) -> Result<Duration, String> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|seconds| *seconds > 0.0)
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| format!("'{value}' is not a positive number of seconds"))
+}
+
+/// Parses a positive number of MiB into bytes, rejecting sizes that don't fit.
+fn parse_mebibytes(value: &str) -> Result<usize, String> {
+    value
+        .parse::<u64>()
+        .ok()
+        .filter(|&mib| mib > 0)
+        .and_then(|mib| mib.checked_mul(1 << 20))
+        .and_then(|bytes| usize::try_from(bytes).ok())
+        .ok_or_else(|| format!("'{value}' is not a positive number of MiB that fits in memory"))
 }
 
 #[derive(Copy, Clone, ValueEnum, Debug)]
@@ -21,61 +85,513 @@ enum CliPart {
     Both,
 }
 
+impl CliPart {
+    fn parts(self) -> Vec<Part> {
+        match self {
+            CliPart::One => vec![Part::One],
+            CliPart::Two => vec![Part::Two],
+            CliPart::Both => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+/// How the runner prints its results.
+#[derive(Copy, Clone, ValueEnum, Debug, PartialEq, Eq)]
+enum OutputFormat {
+    /// Answers for a single day, or a summary table for several
+    Text,
+    /// One JSON object per day and part, one per line
+    Json,
+    /// A TAP version 13 stream with one test per day and part
+    Tap,
+}
+
+#[derive(Copy, Clone, ValueEnum, Debug)]
+enum CliGranularity {
+    Step,
+    Instruction,
+}
+
+impl From<CliGranularity> for Granularity {
+    fn from(granularity: CliGranularity) -> Self {
+        match granularity {
+            CliGranularity::Step => Granularity::Step,
+            CliGranularity::Instruction => Granularity::Instruction,
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Time a day's solver over repeated runs against its input file
+    Bench(BenchArgs),
+    /// Re-run a day's example tests and input whenever its source or data changes
+    Watch(WatchArgs),
+    /// Write a 2022 day 7 terminal session that explores a directory, and
+    /// check the sizes the day's parser finds in it against the disk
+    Transcript(TranscriptArgs),
+    /// Solve one part of a day read from stdin and print the result as JSON.
+    /// The runner solves each part this way under --timeout or --memory-limit
+    #[command(hide = true)]
+    Job(JobArgs),
+}
+
+#[derive(ClapArgs, Debug)]
+struct InputArgs {
+    /// Read the input from this file rather than the day's input.txt, or from stdin if `-`
+    #[arg(long, value_name = "PATH", conflicts_with = "example")]
+    input: Option<PathBuf>,
+    /// Use the day's example.txt, the example saved from the puzzle description
+    #[arg(long)]
+    example: bool,
+}
+
+impl InputArgs {
+    fn source(&self) -> InputSource {
+        InputSource::new(self.input.clone(), self.example)
+    }
+}
+
+#[derive(ClapArgs, Debug)]
+struct BenchArgs {
+    /// The day to benchmark
+    #[arg(short, long)]
+    day: u8,
+    #[arg(short, long, value_enum, default_value_t=CliPart::Both)]
+    part: CliPart,
+    /// The number of timed runs per part
+    #[arg(short = 'n', long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+    /// The number of untimed runs per part before timing starts
+    #[arg(short, long, default_value_t = 5)]
+    warmup: u32,
+    /// Print one JSON object per part instead of a human readable report
+    #[arg(long)]
+    json: bool,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(ClapArgs, Debug)]
+struct WatchArgs {
+    /// The day to watch. Defaults to today during December
+    #[arg(short, long)]
+    day: Option<u8>,
+    /// How often to check for changes, in milliseconds
+    #[arg(long, default_value_t = 500)]
+    interval: u64,
+}
+
+#[derive(ClapArgs, Debug)]
+struct TranscriptArgs {
+    /// The directory to explore
+    dir: PathBuf,
+    /// Write the session to this file rather than stdout
+    #[arg(short, long, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+#[derive(ClapArgs, Debug)]
+struct JobArgs {
+    #[arg(short, long)]
+    day: u8,
+    #[arg(short, long, value_enum)]
+    part: CliPart,
+    /// Exit once the part has allocated more than this many bytes
+    #[arg(long, value_name = "BYTES")]
+    memory_limit: Option<usize>,
+}
+
+#[derive(ClapArgs, Debug)]
+struct VisualizeArgs {
+    /// Draw the day's state after every instruction, or every step if given
+    /// `step`, for days that are simulations
+    #[arg(
+        long,
+        value_enum,
+        value_name = "GRANULARITY",
+        num_args = 0..=1,
+        default_missing_value = "instruction",
+        conflicts_with_all = ["all", "days", "format", "timeout", "memory_limit"]
+    )]
+    visualize: Option<CliGranularity>,
+    /// Milliseconds to wait between frames. In a terminal, each frame then
+    /// replaces the last
+    #[arg(long, value_name = "MS", requires = "visualize")]
+    frame_delay: Option<u64>,
+    /// Save the frames to this file instead of drawing them. `.cast` files are
+    /// asciicast recordings for `asciinema play`, anything else is JSON lines
+    #[arg(long, value_name = "PATH", requires = "visualize")]
+    save_frames: Option<PathBuf>,
+}
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// The day to codegen
-    #[arg(short, long, default_value_t=day_num_from_today())]
-    day: u8,
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// The event year. Defaults to the latest year with solvers
+    #[arg(short, long, global = true)]
+    year: Option<u16>,
+    /// The directory holding each year's data. Defaults to $AOC_DATA_DIR, then
+    /// data_dir in ~/.config/aoc/config.toml, then the puzzles crate's data
+    #[arg(long, global = true)]
+    data_dir: Option<PathBuf>,
+    /// The day to solve. Defaults to today during December, otherwise every day is run
+    #[arg(short, long, conflicts_with_all = ["days", "all"])]
+    day: Option<u8>,
+    /// An inclusive range of days to solve, e.g. `3..7`
+    #[arg(long, value_parser = parse_day_range, conflicts_with = "all")]
+    days: Option<RangeInclusive<u8>>,
+    /// Solve every available day and print a summary table
+    #[arg(short, long)]
+    all: bool,
     #[arg(short, long, value_enum, default_value_t=CliPart::Both)]
     part: CliPart,
+    #[command(flatten)]
+    input: InputArgs,
+    /// Compare answers against each day's answers.toml and fail on any mismatch
+    #[arg(long, conflicts_with_all = ["input", "example"])]
+    verify: bool,
+    /// Save the answers from this run to each day's answers.toml
+    #[arg(long, conflicts_with_all = ["verify", "input", "example"])]
+    record: bool,
+    /// The number of parts to solve at once. Defaults to the number of CPUs
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+    #[command(flatten)]
+    visualize: VisualizeArgs,
+    /// Stop any part that runs for longer than this many seconds
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+    /// Stop any part that allocates more than this many MiB at once
+    #[arg(long, value_name = "MIB", value_parser = parse_mebibytes)]
+    memory_limit: Option<usize>,
+    /// The output format for the results
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
 }
 
-fn solve_with_printout(
-    day: u8,
-    solver: &dyn Puzzle,
-    input: impl Iterator<Item = String> + 'static,
-    part: Part,
-) {
-    let ans = solver.solve(Box::new(input), part).unwrap();
-    println!("Day {day}, part {part:?} answer: {ans}");
+/// The requested year, or the latest one with solvers. Exits if no solvers
+/// are registered for the year.
+fn select_year(year: Option<u16>) -> u16 {
+    let available_years = registry::available_years();
+    match year.or_else(|| available_years.last().copied()) {
+        Some(year) if available_years.contains(&year) => year,
+        _ => {
+            let available_years: Vec<String> = available_years
+                .iter()
+                .map(|year| year.to_string())
+                .collect();
+            eprintln!(
+                "No solvers are registered for {}. Available years: {}",
+                year.map_or("any year".to_string(), |year| year.to_string()),
+                available_years.join(", ")
+            );
+            process::exit(1);
+        }
+    }
+}
+
+fn ensure_day_available(year: u16, day: u8) {
+    if let Err(err) = registry::get_solver(year, day) {
+        let available_days: Vec<String> = registry::available_days(year)
+            .iter()
+            .map(|day| day.to_string())
+            .collect();
+        eprintln!("{err}. Available days: {}", available_days.join(", "));
+        process::exit(1);
+    }
+}
+
+fn print_answers(results: &[PartResult]) {
+    for result in results {
+        let (day, part) = (result.day, result.part);
+        match &result.answer {
+            Ok(ans) => println!("Day {day}, part {part:?} answer: {ans}"),
+            Err(err) => eprintln!("Day {day}, part {part:?} failed: {err}"),
+        }
+    }
+}
+
+/// Solves a single day, drawing or saving each frame its solver emits.
+fn visualize(
+    data_root: &Path,
+    source: &InputSource,
+    year: u16,
+    days: &[u8],
+    parts: &[Part],
+    args: &VisualizeArgs,
+    granularity: Granularity,
+) -> Vec<PartResult> {
+    let &[day] = days else {
+        eprintln!("--visualize can only be used with a single day. Pass the day with --day");
+        process::exit(1);
+    };
+    let delay = args.frame_delay.map(Duration::from_millis);
+    let results = match &args.save_frames {
+        Some(path) => {
+            let delay = delay.unwrap_or(visualize::DEFAULT_FRAME_DELAY);
+            let saved =
+                visualize::FrameWriter::create(path, granularity, delay).and_then(|mut writer| {
+                    let results =
+                        runner::observe_day(data_root, source, year, day, parts, &mut writer);
+                    writer.finish()?;
+                    Ok(results)
+                });
+            saved.unwrap_or_else(|err| {
+                eprintln!("Failed to save frames: {err}");
+                process::exit(1);
+            })
+        }
+        None => {
+            let mut renderer = visualize::TerminalRenderer::new(granularity, delay);
+            runner::observe_day(data_root, source, year, day, parts, &mut renderer)
+        }
+    };
+    results.unwrap_or_else(|| {
+        eprintln!("Day {day} of {year} can't be visualized");
+        process::exit(1);
+    })
+}
+
+fn run_bench(data_root: &Path, year: u16, args: BenchArgs) {
+    let source = args.input.source();
+    if source == InputSource::Stdin {
+        eprintln!(
+            "Stdin can only be read once, so it can't be benchmarked. Save it to a file and pass its path to --input"
+        );
+        process::exit(1);
+    }
+    for part in args.part.parts() {
+        match bench::bench_part(
+            data_root,
+            &source,
+            year,
+            args.day,
+            part,
+            args.iterations,
+            args.warmup,
+        ) {
+            Ok(result) if args.json => result.print_json(year),
+            Ok(result) => result.print_human(),
+            Err(err) => {
+                eprintln!("Day {}, part {part:?} failed: {err}", args.day);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn run_watch(data_root: &Path, year: u16, args: WatchArgs) {
+    let Some(day) = args.day.or_else(day_num_from_today) else {
+        eprintln!("Pass the day to watch with --day");
+        process::exit(1);
+    };
+    if let Err(err) = watch::watch(data_root, year, day, Duration::from_millis(args.interval)) {
+        eprintln!("Failed to watch day {day}: {err}");
+        process::exit(1);
+    }
+}
+
+fn run_transcript(args: TranscriptArgs) {
+    let recorded = transcript::record(&args.dir).unwrap_or_else(|err| {
+        eprintln!("Failed to read {}: {err}", args.dir.display());
+        process::exit(1);
+    });
+    let session = recorded.lines.join("\n") + "\n";
+    let written = match &args.output {
+        Some(path) => fs::write(path, session),
+        None => io::stdout().write_all(session.as_bytes()),
+    };
+    if let Err(err) = written {
+        eprintln!("Failed to write the session: {err}");
+        process::exit(1);
+    }
+    for path in &recorded.skipped {
+        eprintln!("Skipped {}", path.display());
+    }
+
+    let mismatches = transcript::check(&recorded).unwrap_or_else(|err| {
+        eprintln!("The session couldn't be parsed: {err}");
+        process::exit(1);
+    });
+    let describe =
+        |size: Option<usize>| size.map_or("missing".to_string(), |size| size.to_string());
+    for mismatch in &mismatches {
+        eprintln!(
+            "{}: {} bytes on disk, {} parsed",
+            mismatch.path,
+            describe(mismatch.on_disk),
+            describe(mismatch.parsed)
+        );
+    }
+    if !mismatches.is_empty() {
+        process::exit(1);
+    }
+    eprintln!(
+        "The parsed sizes of all {} directories match the disk",
+        recorded.sizes.len()
+    );
+}
+
+fn run_job(year: u16, args: JobArgs) {
+    let &[part] = &args.part.parts()[..] else {
+        eprintln!("A job solves a single part");
+        process::exit(2);
+    };
+    let lines = io::stdin()
+        .lines()
+        .collect::<io::Result<Vec<String>>>()
+        .unwrap_or_else(|err| {
+            eprintln!("Failed to read the job's input: {err}");
+            process::exit(1);
+        });
+    let output = runner::solve_job(year, args.day, part, lines, args.memory_limit);
+    println!(
+        "{}",
+        serde_json::to_string(&output).expect("Job output can be serialized")
+    );
 }
 
 fn main() {
     let args = Args::parse();
-    let day = args.day;
-    let solver: Box<dyn Puzzle> = match args.day {
-        1 => day_1::Solver::new(),
-        2 => day_2::Solver::new(),
-        3 => day_3::Solver::new(),
-        4 => day_4::Solver::new(),
-        5 => day_5::Solver::new(),
-        6 => day_6::Solver::new(),
-        7 => day_7::Solver::new(),
-        8 => day_8::Solver::new(),
-        9 => day_9::Solver::new(),
-        _ => panic!("Unsupported test day"),
+    let year = select_year(args.year);
+    if let Some(Command::Job(job_args)) = args.command {
+        run_job(year, job_args);
+        return;
+    }
+    let data_root = match data::resolve_data_root(args.data_dir) {
+        Ok(data_root) => data_root,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
     };
+    match args.command {
+        Some(Command::Bench(bench_args)) => {
+            run_bench(&data_root, year, bench_args);
+            return;
+        }
+        Some(Command::Watch(watch_args)) => {
+            run_watch(&data_root, year, watch_args);
+            return;
+        }
+        Some(Command::Transcript(transcript_args)) => {
+            run_transcript(transcript_args);
+            return;
+        }
+        Some(Command::Job(_)) => unreachable!("Jobs are run before the data directory is found"),
+        None => {}
+    }
 
-    let input = File::open(format!("{DATA_FILE_PATH}/day_{day}/{INPUT_FILE_NAME}"))
-        .expect(&format!("Failed to open input file for day {day}"));
-    let input = BufReader::new(input)
-        .lines()
-        .map(|line| line.expect("Failed to read IO for the given line"));
-    let input = Box::new(input);
-
-    match args.part {
-        CliPart::One => solve_with_printout(day, solver.as_ref(), input, Part::One),
-        CliPart::Two => solve_with_printout(day, solver.as_ref(), input, Part::Two),
-        CliPart::Both => {
-            solve_with_printout(day, solver.as_ref(), input, Part::One);
-            let input = File::open(format!("{DATA_FILE_PATH}/day_{day}/{INPUT_FILE_NAME}"))
-                .expect(&format!("Failed to open input file for day {day}"));
-            let input = BufReader::new(input)
-                .lines()
-                .map(|line| line.expect("Failed to read IO for the given line"));
-            let input = Box::new(input);
-            solve_with_printout(day, solver.as_ref(), input, Part::Two);
+    let parts = args.part.parts();
+
+    let day = if args.all || args.days.is_some() {
+        None
+    } else {
+        args.day.or_else(day_num_from_today)
+    };
+    let days: Vec<u8> = match (day, args.days) {
+        (Some(day), _) => {
+            ensure_day_available(year, day);
+            vec![day]
+        }
+        (None, Some(range)) => registry::available_days(year)
+            .into_iter()
+            .filter(|day| range.contains(day))
+            .collect(),
+        (None, None) => registry::available_days(year),
+    };
+    let source = args.input.source();
+    if !source.is_per_day() && days.len() != 1 {
+        eprintln!("--input can only be used to solve a single day. Pass the day with --day");
+        process::exit(1);
+    }
+
+    let jobs = args.jobs.map_or_else(
+        || thread::available_parallelism().map_or(1, usize::from),
+        |jobs| jobs as usize,
+    );
+    let limits = Limits {
+        timeout: args.timeout,
+        memory: args.memory_limit,
+    };
+    let mut results = match args.visualize.visualize {
+        Some(granularity) => visualize(
+            &data_root,
+            &source,
+            year,
+            &days,
+            &parts,
+            &args.visualize,
+            granularity.into(),
+        ),
+        None => runner::run_days(&data_root, &source, year, &days, &parts, jobs, limits),
+    };
+    if args.verify {
+        if let Err(err) = answers::verify_results(&data_root, &mut results) {
+            eprintln!("{err}");
+            process::exit(1);
         }
     }
+
+    match args.format {
+        OutputFormat::Text => {
+            if day.is_some() {
+                print_answers(&results);
+            } else {
+                runner::print_summary_table(&results);
+            }
+            runner::print_parse_errors(&results);
+            if args.verify {
+                answers::print_mismatches(&results);
+            }
+        }
+        OutputFormat::Json => report::print_json(&results),
+        OutputFormat::Tap => report::print_tap(&results),
+    }
+    if args.record {
+        if let Err(err) = answers::record_results(&data_root, &results) {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    }
+    // A single day can't be solved without its input, but days without one
+    // are passed over when running several
+    let skipped_day = day.is_some() && results.iter().any(PartResult::skipped);
+    if skipped_day || results.iter().any(PartResult::failed) {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_day_range() {
+        assert_eq!(parse_day_range("5"), Ok(5..=5));
+        assert_eq!(parse_day_range("3..7"), Ok(3..=7));
+        assert_eq!(parse_day_range("3..=7"), Ok(3..=7));
+        assert!(parse_day_range("7..3").is_err());
+        assert!(parse_day_range("a..3").is_err());
+    }
+
+    #[test]
+    fn test_parse_seconds() {
+        assert_eq!(parse_seconds("2"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_seconds("0.5"), Ok(Duration::from_millis(500)));
+        assert!(parse_seconds("0").is_err());
+        assert!(parse_seconds("-1").is_err());
+        assert!(parse_seconds("soon").is_err());
+    }
+
+    #[test]
+    fn test_parse_mebibytes() {
+        assert_eq!(parse_mebibytes("512"), Ok(512 << 20));
+        assert!(parse_mebibytes("0").is_err());
+        assert!(parse_mebibytes("17592186044416").is_err());
+        assert!(parse_mebibytes("lots").is_err());
+    }
 }
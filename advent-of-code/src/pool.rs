@@ -0,0 +1,144 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, Once};
+use std::thread;
+
+thread_local! {
+    /// Whether this thread is running code under `catch_panic`.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// Where the last caught panic on this thread was raised.
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// A panic caught by `catch_panic`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// The `file:line:column` the panic was raised at
+    pub location: Option<String>,
+}
+
+/// Replaces the panic hook with one that records the location of panics
+/// raised under `catch_panic` instead of printing them, since they're
+/// reported along with the rest of the results. Other panics are passed to
+/// the previous hook.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                LOCATION.set(info.location().map(ToString::to_string));
+            } else {
+                previous_hook(info);
+            }
+        }));
+    });
+}
+
+/// Runs `f`, turning a panic into an error holding its message and location.
+pub fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, Panic> {
+    install_panic_hook();
+    let was_catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(was_catching);
+    result.map_err(|payload| Panic {
+        message: panic_message(payload.as_ref()),
+        location: LOCATION.take(),
+    })
+}
+
+/// The text of a panic, for panics raised with a string message.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// Runs `job` on every item using up to `workers` threads, returning the
+/// results in the same order as `items` regardless of which finishes first.
+///
+/// A job that panics yields the panic rather than taking down the rest of
+/// the jobs.
+pub fn run_parallel<T, R, F>(items: &[T], workers: usize, job: F) -> Vec<Result<R, Panic>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<R, Panic>>>> =
+        Mutex::new(items.iter().map(|_| None).collect());
+    let worker = || loop {
+        let index = next.fetch_add(1, Ordering::Relaxed);
+        let Some(item) = items.get(index) else {
+            break;
+        };
+        let result = catch_panic(|| job(item));
+        results
+            .lock()
+            .expect("Workers don't panic while holding the lock")[index] = Some(result);
+    };
+
+    thread::scope(|scope| {
+        for _ in 1..workers.clamp(1, items.len().max(1)) {
+            scope.spawn(worker);
+        }
+        worker();
+    });
+    results
+        .into_inner()
+        .expect("Workers don't panic while holding the lock")
+        .into_iter()
+        .map(|result| result.expect("Every job is run once all workers finish"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_run_parallel_keeps_order() {
+        let items: Vec<u64> = (0..20).collect();
+        let results = run_parallel(&items, 4, |&item| {
+            // Later items finish first
+            thread::sleep(Duration::from_millis(20 - item));
+            item * 2
+        });
+        let expected: Vec<Result<u64, Panic>> = items.iter().map(|item| Ok(item * 2)).collect();
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn test_run_parallel_isolates_panics() {
+        let results = run_parallel(&[1, 2, 3], 2, |&item| {
+            if item == 2 {
+                panic!("job {item} failed");
+            }
+            item
+        });
+        assert_eq!(results[0], Ok(1));
+        assert_eq!(results[2], Ok(3));
+        let panic = results[1].as_ref().unwrap_err();
+        assert_eq!(panic.message, "job 2 failed");
+        assert!(panic.location.as_ref().unwrap().contains("pool.rs"));
+    }
+
+    #[test]
+    fn test_catch_panic_message() {
+        assert_eq!(catch_panic(|| 5), Ok(5));
+        let panic = catch_panic(|| -> u8 { panic!("{} went wrong", "it") }).unwrap_err();
+        assert_eq!(panic.message, "it went wrong");
+        let panic = catch_panic(|| std::panic::panic_any(5)).unwrap_err();
+        assert_eq!(panic.message, "Box<dyn Any>");
+    }
+}
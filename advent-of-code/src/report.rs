@@ -0,0 +1,206 @@
+use serde_json::json;
+
+use aoc_puzzles::known_answers::Verification;
+
+use crate::runner::PartResult;
+
+/// A single result as a JSON object, in the same shape as `bench --json`.
+pub fn result_json(result: &PartResult) -> serde_json::Value {
+    let error = result.answer.as_ref().err().map(|err| {
+        json!({
+            "kind": err.kind(),
+            "message": err.to_string(),
+        })
+    });
+    let expected = match &result.verification {
+        Verification::Incorrect { expected } => Some(expected),
+        _ => None,
+    };
+    json!({
+        "year": result.year,
+        "day": result.day,
+        "part": format!("{:?}", result.part),
+        "status": result.outcome(),
+        "answer": result.answer.as_ref().ok(),
+        "expected": expected,
+        "parse_ns": result.parse_elapsed.as_nanos() as u64,
+        "solve_ns": result.elapsed.as_nanos() as u64,
+        "error": error,
+    })
+}
+
+/// Prints one JSON object per line, one line per day and part.
+pub fn print_json(results: &[PartResult]) {
+    for result in results {
+        println!("{}", result_json(result));
+    }
+}
+
+/// A field of a TAP YAML block. The value is written as a JSON string, which
+/// is also valid YAML, so multi-line answers and messages stay on one line.
+fn yaml_field(name: &str, value: &str) -> String {
+    format!("  {name}: {}", serde_json::Value::from(value))
+}
+
+/// Escapes text for a test line's description, where an unescaped `#`
+/// would start a directive such as `# SKIP`.
+fn tap_description(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('#', "\\#")
+}
+
+/// Renders the results as a TAP version 13 stream. Errors and wrong answers
+/// are failing tests, with the details in a YAML block.
+pub fn tap(results: &[PartResult]) -> String {
+    let mut lines = vec![
+        "TAP version 13".to_string(),
+        format!("1..{}", results.len()),
+    ];
+    for (index, result) in results.iter().enumerate() {
+        let number = index + 1;
+        let description = format!("{} day {}, part {:?}", result.year, result.day, result.part);
+        if let (true, Err(err)) = (result.skipped(), &result.answer) {
+            let reason = tap_description(&err.to_string());
+            lines.push(format!("ok {number} - {description} # SKIP {reason}"));
+            continue;
+        }
+        if !result.failed() {
+            let answer = result
+                .answer
+                .as_ref()
+                .expect("Results without an answer failed");
+            let answer = tap_description(&answer.to_string().replace('\n', " / "));
+            lines.push(format!("ok {number} - {description}: {answer}"));
+            continue;
+        }
+
+        lines.push(format!("not ok {number} - {description}"));
+        lines.push("  ---".to_string());
+        match (&result.answer, &result.verification) {
+            (Err(err), _) => {
+                lines.push(yaml_field("kind", err.kind()));
+                lines.push(yaml_field("message", &err.to_string()));
+            }
+            (Ok(found), Verification::Incorrect { expected }) => {
+                lines.push(yaml_field("kind", "wrong_answer"));
+                lines.push(yaml_field("expected", &expected.to_string()));
+                lines.push(yaml_field("found", &found.to_string()));
+            }
+            (Ok(_), _) => unreachable!("Only errors and wrong answers fail"),
+        }
+        lines.push("  ...".to_string());
+    }
+    lines.join("\n")
+}
+
+pub fn print_tap(results: &[PartResult]) {
+    println!("{}", tap(results));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use aoc_puzzles::{Answer, Part, PuzzleError};
+
+    use super::*;
+
+    fn result(
+        part: Part,
+        answer: Result<Answer, PuzzleError>,
+        verification: Verification,
+    ) -> PartResult {
+        PartResult {
+            year: 2022,
+            day: 5,
+            part,
+            answer: answer.map_err(Arc::new),
+            parse_elapsed: Duration::from_micros(3),
+            elapsed: Duration::from_micros(12),
+            verification,
+        }
+    }
+
+    #[test]
+    fn test_result_json() {
+        let ok = result(Part::One, Ok(Answer::from("CMZ")), Verification::Correct);
+        assert_eq!(
+            result_json(&ok),
+            json!({
+                "year": 2022,
+                "day": 5,
+                "part": "One",
+                "status": "correct",
+                "answer": "CMZ",
+                "expected": null,
+                "parse_ns": 3000,
+                "solve_ns": 12000,
+                "error": null,
+            })
+        );
+
+        let failed = result(
+            Part::Two,
+            Err(PuzzleError::NoSolutionFound),
+            Verification::Unverified,
+        );
+        let value = result_json(&failed);
+        assert_eq!(value["status"], "error");
+        assert_eq!(value["answer"], serde_json::Value::Null);
+        assert_eq!(
+            value["error"],
+            json!({"kind": "no_solution", "message": "No solution was found"})
+        );
+    }
+
+    #[test]
+    fn test_tap() {
+        let results = [
+            result(Part::One, Ok(Answer::from(157)), Verification::Unverified),
+            result(
+                Part::Two,
+                Ok(Answer::from(70)),
+                Verification::Incorrect {
+                    expected: Answer::from(71),
+                },
+            ),
+            result(
+                Part::Two,
+                Err(PuzzleError::NoSolutionFound),
+                Verification::Unverified,
+            ),
+            result(
+                Part::Two,
+                Ok(Answer::grid(["#..#", "# SKIP \\"])),
+                Verification::Correct,
+            ),
+            result(
+                Part::One,
+                Err(PuzzleError::MissingInput {
+                    day: 5,
+                    path: "data/2022/day_5/input.txt".into(),
+                }),
+                Verification::Unverified,
+            ),
+        ];
+        assert_eq!(
+            tap(&results),
+            "TAP version 13
+1..5
+ok 1 - 2022 day 5, part One: 157
+not ok 2 - 2022 day 5, part Two
+  ---
+  kind: \"wrong_answer\"
+  expected: \"71\"
+  found: \"70\"
+  ...
+not ok 3 - 2022 day 5, part Two
+  ---
+  kind: \"no_solution\"
+  message: \"No solution was found\"
+  ...
+ok 4 - 2022 day 5, part Two: \\#..\\# / \\# SKIP \\\\
+ok 5 - 2022 day 5, part One # SKIP Day 5 has no input at data/2022/day_5/input.txt. Download it with aoc-codegen"
+        );
+    }
+}
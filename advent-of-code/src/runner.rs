@@ -0,0 +1,688 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+use aoc_puzzles::known_answers::Verification;
+use aoc_puzzles::{data, registry, Answer, Observer, ParsedInput, Part, PuzzleError};
+use serde::{Deserialize, Serialize};
+
+use crate::limits::{self, Interrupted, Limits};
+use crate::pool;
+
+const INPUT_FILE_NAME: &str = "input.txt";
+const EXAMPLE_FILE_NAME: &str = "example.txt";
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's `input.txt` in the data directory
+    Puzzle,
+    /// The day's `example.txt`, saved from the puzzle description by aoc-codegen
+    Example,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// `--input -` reads from stdin, any other path is read as a file.
+    pub fn new(input: Option<PathBuf>, example: bool) -> Self {
+        match input {
+            Some(path) if path.as_os_str() == "-" => Self::Stdin,
+            Some(path) => Self::File(path),
+            None if example => Self::Example,
+            None => Self::Puzzle,
+        }
+    }
+
+    /// Whether the input is chosen per day, rather than being one input
+    /// that only makes sense for a single day.
+    pub fn is_per_day(&self) -> bool {
+        matches!(self, Self::Puzzle | Self::Example)
+    }
+}
+
+/// The outcome of solving a single part of a single day.
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Result<Answer, Arc<PuzzleError>>,
+    /// Time spent parsing the input, which is shared by every part of a day
+    pub parse_elapsed: Duration,
+    pub elapsed: Duration,
+    pub verification: Verification,
+}
+
+impl PartResult {
+    /// One of `ok`, `correct`, `wrong`, `skipped` or `error`.
+    pub fn outcome(&self) -> &'static str {
+        match (&self.answer, &self.verification) {
+            _ if self.skipped() => "skipped",
+            (Err(_), _) => "error",
+            (Ok(_), Verification::Unverified) => "ok",
+            (Ok(_), Verification::Correct) => "correct",
+            (Ok(_), Verification::Incorrect { .. }) => "wrong",
+        }
+    }
+
+    pub fn status(&self) -> String {
+        match &self.answer {
+            Err(err) => format!("{}: {err}", self.outcome()),
+            Ok(_) => self.outcome().to_string(),
+        }
+    }
+
+    /// Days without a saved input are skipped rather than failed, so that
+    /// running every day works before every input has been downloaded.
+    pub fn skipped(&self) -> bool {
+        matches!(&self.answer, Err(err) if matches!(**err, PuzzleError::MissingInput { .. }))
+    }
+
+    pub fn failed(&self) -> bool {
+        (self.answer.is_err() && !self.skipped())
+            || matches!(self.verification, Verification::Incorrect { .. })
+    }
+}
+
+/// Opens a file, naming it in the error if it can't be opened.
+fn open_file(path: &Path) -> Result<Box<dyn BufRead>, PuzzleError> {
+    let file = File::open(path)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+pub fn open_input(
+    data_root: &Path,
+    year: u16,
+    day: u8,
+    source: &InputSource,
+) -> Result<Box<dyn Iterator<Item = String>>, PuzzleError> {
+    let day_dir = data::day_data_dir(data_root, year, day);
+    let day_file = |name: &str| {
+        let path = day_dir.join(name);
+        if path.exists() {
+            open_file(&path)
+        } else {
+            Err(PuzzleError::MissingInput { day, path })
+        }
+    };
+    let reader = match source {
+        InputSource::Puzzle => day_file(INPUT_FILE_NAME)?,
+        InputSource::Example => day_file(EXAMPLE_FILE_NAME)?,
+        InputSource::File(path) => open_file(path)?,
+        InputSource::Stdin => Box::new(io::stdin().lock()),
+    };
+    Ok(Box::new(reader.lines().map(|line| {
+        line.expect("Failed to read IO for the given line")
+    })))
+}
+
+/// A day's parsed input, or the error that stopped it being parsed, along
+/// with how long parsing took.
+type ParseOutcome = (Result<Arc<dyn ParsedInput>, Arc<PuzzleError>>, Duration);
+
+/// One day of a run. Its input is parsed by whichever of its parts' jobs
+/// starts first, and any other part waits for that parse to finish. Under
+/// limits, each part parses the input itself and only the lines are shared.
+struct DayInput {
+    day: u8,
+    lines: OnceLock<Result<Vec<String>, Arc<PuzzleError>>>,
+    parsed: OnceLock<ParseOutcome>,
+}
+
+/// What a job process reports back after solving one part of a day.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobOutput {
+    answer: Result<Answer, PuzzleError>,
+    parse_elapsed: Duration,
+    elapsed: Duration,
+}
+
+/// Checks the day has a solver and reads the whole of its input.
+fn read_day(
+    data_root: &Path,
+    source: &InputSource,
+    year: u16,
+    day: u8,
+) -> Result<Vec<String>, PuzzleError> {
+    registry::get_solver(year, day)?;
+    Ok(open_input(data_root, year, day, source)?.collect())
+}
+
+/// Parses a day's input, catching any panic in the parser.
+fn parse_lines(
+    year: u16,
+    day: u8,
+    lines: Vec<String>,
+) -> (Result<Box<dyn ParsedInput>, PuzzleError>, Duration) {
+    let start = Instant::now();
+    let parsed = registry::get_solver(year, day).and_then(|solver| {
+        pool::catch_panic(|| solver.parse(Box::new(lines.into_iter())))
+            .unwrap_or_else(|panic| Err(interrupted_error(day, None, Interrupted::Panicked(panic))))
+    });
+    (parsed, start.elapsed())
+}
+
+fn parse_day(data_root: &Path, source: &InputSource, year: u16, day: u8) -> ParseOutcome {
+    // Read ahead of time so that only the parser itself is timed
+    match read_day(data_root, source, year, day) {
+        Ok(lines) => {
+            let (parsed, parse_elapsed) = parse_lines(year, day, lines);
+            (parsed.map(Arc::from).map_err(Arc::new), parse_elapsed)
+        }
+        Err(err) => (Err(Arc::new(err)), Duration::ZERO),
+    }
+}
+
+/// Solves one part, catching any panic in the solver.
+fn solve_part(day: u8, parsed: &dyn ParsedInput, part: Part) -> Result<Answer, PuzzleError> {
+    pool::catch_panic(|| parsed.solve(part)).unwrap_or_else(|panic| {
+        Err(interrupted_error(
+            day,
+            Some(part),
+            Interrupted::Panicked(panic),
+        ))
+    })
+}
+
+fn interrupted_error(day: u8, part: Option<Part>, interrupted: Interrupted) -> PuzzleError {
+    match interrupted {
+        Interrupted::Panicked(panic) => PuzzleError::SolverPanicked {
+            day,
+            part,
+            message: panic.message,
+            location: panic.location,
+        },
+        Interrupted::TimedOut(timeout) => PuzzleError::TimedOut { day, part, timeout },
+        Interrupted::MemoryLimitExceeded(limit) => {
+            PuzzleError::MemoryLimitExceeded { day, part, limit }
+        }
+    }
+}
+
+/// Solves one part of a day from `lines` for the runner's job subcommand,
+/// exiting the process if it allocates more than `memory` bytes.
+pub fn solve_job(
+    year: u16,
+    day: u8,
+    part: Part,
+    lines: Vec<String>,
+    memory: Option<usize>,
+) -> JobOutput {
+    limits::set_memory_limit(memory);
+    let (parsed, parse_elapsed) = parse_lines(year, day, lines);
+    let start = Instant::now();
+    let answer = parsed.and_then(|parsed| solve_part(day, parsed.as_ref(), part));
+    let elapsed = start.elapsed();
+    // Reporting the answer isn't part of the job
+    limits::set_memory_limit(None);
+    JobOutput {
+        answer,
+        parse_elapsed,
+        elapsed,
+    }
+}
+
+/// The command that runs this executable's job subcommand for one part.
+fn job_command(year: u16, day: u8, part: Part, memory: Option<usize>) -> Command {
+    let runner = env::current_exe().expect("The runner's executable can be found");
+    let mut command = Command::new(runner);
+    let part = match part {
+        Part::One => "one",
+        Part::Two => "two",
+    };
+    let (year, day) = (year.to_string(), day.to_string());
+    command.args(["job", "--year", &year, "--day", &day, "--part", part]);
+    if let Some(memory) = memory {
+        command.args(["--memory-limit", &memory.to_string()]);
+    }
+    command
+}
+
+/// Solves one part of a day in a process of its own, which exits if it
+/// allocates past the memory limit and is killed if it runs past the timeout.
+fn run_part_in_job(
+    data_root: &Path,
+    source: &InputSource,
+    year: u16,
+    day_input: &DayInput,
+    part: Part,
+    limits: Limits,
+) -> PartResult {
+    let day = day_input.day;
+    let start = Instant::now();
+    let lines = day_input
+        .lines
+        .get_or_init(|| read_day(data_root, source, year, day).map_err(Arc::new));
+    let output = match lines {
+        Ok(lines) => {
+            limits::run_job::<JobOutput>(limits, job_command(year, day, part, limits.memory), lines)
+                .map_err(|interrupted| Arc::new(interrupted_error(day, Some(part), interrupted)))
+        }
+        Err(err) => Err(err.clone()),
+    };
+    let (answer, parse_elapsed, elapsed) = match output {
+        Ok(output) => (
+            output.answer.map_err(Arc::new),
+            output.parse_elapsed,
+            output.elapsed,
+        ),
+        Err(err) => (Err(err), Duration::ZERO, start.elapsed()),
+    };
+    PartResult {
+        year,
+        day,
+        part,
+        answer,
+        parse_elapsed,
+        elapsed,
+        verification: Verification::Unverified,
+    }
+}
+
+/// Solves one part of a day, capturing any `PuzzleError`, panic or broken
+/// limit rather than aborting.
+fn run_part(
+    data_root: &Path,
+    source: &InputSource,
+    year: u16,
+    day_input: &DayInput,
+    part: Part,
+    limits: Limits,
+) -> PartResult {
+    if limits != Limits::default() {
+        return run_part_in_job(data_root, source, year, day_input, part, limits);
+    }
+    let day = day_input.day;
+    let (parsed, parse_elapsed) = day_input
+        .parsed
+        .get_or_init(|| parse_day(data_root, source, year, day));
+    let start = Instant::now();
+    let answer = match parsed {
+        Ok(parsed) => solve_part(day, parsed.as_ref(), part).map_err(Arc::new),
+        Err(err) => Err(err.clone()),
+    };
+    PartResult {
+        year,
+        day,
+        part,
+        answer,
+        parse_elapsed: *parse_elapsed,
+        elapsed: start.elapsed(),
+        verification: Verification::Unverified,
+    }
+}
+
+/// Solves the requested parts of each day across `jobs` worker threads,
+/// parsing each day's input only once. Results are in day then part order,
+/// and a solver that panics or breaks a limit only fails its own part.
+///
+/// Under limits, each part is solved in a process of its own so that it can
+/// be stopped, and parses its day's input again.
+///
+/// Parts run concurrently, so their timings include contention with other
+/// jobs. Pass a single job for comparable timings.
+pub fn run_days(
+    data_root: &Path,
+    source: &InputSource,
+    year: u16,
+    days: &[u8],
+    parts: &[Part],
+    jobs: usize,
+    limits: Limits,
+) -> Vec<PartResult> {
+    let day_inputs: Vec<DayInput> = days
+        .iter()
+        .map(|&day| DayInput {
+            day,
+            lines: OnceLock::new(),
+            parsed: OnceLock::new(),
+        })
+        .collect();
+    let part_jobs: Vec<(&DayInput, Part)> = day_inputs
+        .iter()
+        .flat_map(|day_input| parts.iter().map(move |&part| (day_input, part)))
+        .collect();
+
+    pool::run_parallel(&part_jobs, jobs, |&(day_input, part)| {
+        run_part(data_root, source, year, day_input, part, limits)
+    })
+    .into_iter()
+    .zip(&part_jobs)
+    .map(|(result, &(day_input, part))| {
+        // Panics in the solver itself are caught by `run_part`. This catches
+        // the rest, such as failing to read the input.
+        result.unwrap_or_else(|panic| PartResult {
+            year,
+            day: day_input.day,
+            part,
+            answer: Err(Arc::new(interrupted_error(
+                day_input.day,
+                Some(part),
+                Interrupted::Panicked(panic),
+            ))),
+            parse_elapsed: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            verification: Verification::Unverified,
+        })
+    })
+    .collect()
+}
+
+/// Solves the requested parts of a day on this thread, passing every frame
+/// its solver emits to `observer`. Returns `None` if the day can't be observed.
+pub fn observe_day(
+    data_root: &Path,
+    source: &InputSource,
+    year: u16,
+    day: u8,
+    parts: &[Part],
+    observer: &mut dyn Observer,
+) -> Option<Vec<PartResult>> {
+    let (parsed, parse_elapsed) = parse_day(data_root, source, year, day);
+    let mut results = vec![];
+    for &part in parts {
+        let start = Instant::now();
+        let answer = match &parsed {
+            Ok(parsed) => parsed.observe(part, observer)?.map_err(Arc::new),
+            Err(err) => Err(err.clone()),
+        };
+        results.push(PartResult {
+            year,
+            day,
+            part,
+            answer,
+            parse_elapsed,
+            elapsed: start.elapsed(),
+            verification: Verification::Unverified,
+        });
+    }
+    Some(results)
+}
+
+/// Names the parts that hit an error, e.g. `part One` or `parts One and Two`.
+fn describe_parts(parts: &[Part]) -> String {
+    let names: Vec<String> = parts.iter().map(|part| format!("{part:?}")).collect();
+    match &names[..] {
+        [name] => format!("part {name}"),
+        [rest @ .., last] => format!("parts {} and {last}", rest.join(", ")),
+        [] => "no parts".to_string(),
+    }
+}
+
+/// Renders a parse error as a snippet of the offending line with a caret
+/// under the column it points at, naming the `parts` that were being solved
+/// when it was hit. Other errors have no snippet.
+pub fn render_parse_error(err: &PuzzleError, parts: &[Part]) -> Option<String> {
+    let PuzzleError::ParseError {
+        line_number,
+        column,
+        line,
+        found,
+        ..
+    } = err
+    else {
+        return None;
+    };
+    let gutter = " ".repeat(line_number.to_string().len());
+    // Columns are one-based, but nothing stops a solver from passing zero
+    let indent = column.saturating_sub(1);
+    let found_here = line.chars().skip(indent).collect::<String>();
+    let caret_len = if !found.is_empty() && found_here.starts_with(found.as_str()) {
+        found.chars().count()
+    } else {
+        1
+    };
+    Some(format!(
+        "error: {err}\n{gutter}--> line {line_number}, column {column}, solving {}\n{gutter} |\n{line_number} | {line}\n{gutter} | {}{}",
+        describe_parts(parts),
+        " ".repeat(indent),
+        "^".repeat(caret_len)
+    ))
+}
+
+/// Prints a snippet for every parse error, once per day along with every
+/// part that hit it, since a day's parts parse the same input.
+pub fn print_parse_errors(results: &[PartResult]) {
+    let mut errors: Vec<(u8, &PuzzleError, Vec<Part>)> = vec![];
+    for result in results {
+        let Err(err) = &result.answer else {
+            continue;
+        };
+        if !matches!(**err, PuzzleError::ParseError { .. }) {
+            continue;
+        }
+        let seen = errors
+            .iter_mut()
+            .find(|(day, seen, _)| *day == result.day && seen.to_string() == err.to_string());
+        match seen {
+            Some((_, _, parts)) => parts.push(result.part),
+            None => errors.push((result.day, err, vec![result.part])),
+        }
+    }
+    for (_, err, parts) in errors {
+        if let Some(snippet) = render_parse_error(err, &parts) {
+            eprintln!("\n{snippet}");
+        }
+    }
+}
+
+pub fn print_summary_table(results: &[PartResult]) {
+    const HEADERS: [&str; 6] = ["Day", "Part", "Answer", "Status", "Parse", "Solve"];
+    let rows: Vec<[String; 6]> = results
+        .iter()
+        .map(|result| {
+            [
+                result.day.to_string(),
+                format!("{:?}", result.part),
+                result
+                    .answer
+                    .as_ref()
+                    .map(|answer| answer.to_string().replace('\n', " / "))
+                    .unwrap_or_default(),
+                result.status(),
+                format!("{:.2?}", result.parse_elapsed),
+                format!("{:.2?}", result.elapsed),
+            ]
+        })
+        .collect();
+
+    let mut widths = HEADERS.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = std::cmp::max(*width, cell.len());
+        }
+    }
+
+    let print_row = |cells: [&str; 6]| {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", line.join(" | ").trim_end());
+    };
+    print_row(HEADERS);
+    let separator: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+    println!("{}", separator.join("-+-"));
+    for row in &rows {
+        print_row([&row[0], &row[1], &row[2], &row[3], &row[4], &row[5]]);
+    }
+
+    let failures = results.iter().filter(|result| result.failed()).count();
+    let skipped = results.iter().filter(|result| result.skipped()).count();
+    print!(
+        "\n{} solved, {failures} failed",
+        results.len() - failures - skipped
+    );
+    if skipped > 0 {
+        print!(", {skipped} skipped");
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_puzzles::{register_solver, InputLine, Solution};
+
+    use super::*;
+
+    /// A solver whose part two always panics, registered under a year no
+    /// puzzles exist for.
+    struct PanickingSolver;
+
+    register_solver!(1, 1, PanickingSolver);
+
+    impl Solution for PanickingSolver {
+        type Input = Vec<String>;
+
+        fn parse(input: Box<dyn Iterator<Item = String>>) -> Result<Self::Input, PuzzleError> {
+            Ok(input.collect())
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Answer, PuzzleError> {
+            Ok(input.len().into())
+        }
+
+        fn part_two(_input: &Self::Input) -> Result<Answer, PuzzleError> {
+            panic!("Part two always panics")
+        }
+    }
+
+    #[test]
+    fn test_render_parse_error() {
+        let text = "R 4\nU x";
+        let err = registry::get_solver(2022, 9)
+            .unwrap()
+            .parse(Box::new(text.lines().map(str::to_string)))
+            .err()
+            .unwrap();
+        let snippet = render_parse_error(&err, &[Part::Two]).unwrap();
+        let lines: Vec<&str> = snippet.lines().skip(1).collect();
+        assert_eq!(
+            lines,
+            [
+                " --> line 2, column 3, solving part Two",
+                "  |",
+                "2 | U x",
+                "  |   ^"
+            ]
+        );
+
+        let snippet = render_parse_error(&err, &[Part::One, Part::Two]).unwrap();
+        assert!(snippet.contains("column 3, solving parts One and Two\n"));
+    }
+
+    #[test]
+    fn test_render_parse_error_underlines_token() {
+        let line = InputLine::new(5, 0, "move 3 from ab to 2");
+        let token = line.token().split(" ").nth(3).unwrap();
+        let err = line.error_at(token, "a stack number");
+        let snippet = render_parse_error(&err, &[Part::One]).unwrap();
+        assert!(snippet.ends_with("1 | move 3 from ab to 2\n  |             ^^"));
+        assert!(render_parse_error(&PuzzleError::NoSolutionFound, &[Part::One]).is_none());
+
+        let err = line.error(0, "a move", "m");
+        let snippet = render_parse_error(&err, &[Part::One]).unwrap();
+        assert!(snippet.ends_with("1 | move 3 from ab to 2\n  | ^"));
+    }
+
+    #[test]
+    fn test_input_source_new() {
+        assert_eq!(InputSource::new(None, false), InputSource::Puzzle);
+        assert_eq!(InputSource::new(None, true), InputSource::Example);
+        assert_eq!(
+            InputSource::new(Some(PathBuf::from("-")), false),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::new(Some(PathBuf::from("edge_case.txt")), false),
+            InputSource::File(PathBuf::from("edge_case.txt"))
+        );
+    }
+
+    #[test]
+    fn test_run_day_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.txt");
+        std::fs::write(&path, "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n").unwrap();
+        let source = InputSource::File(path.clone());
+        let results = run_days(
+            Path::new("/nonexistent"),
+            &source,
+            2022,
+            &[9],
+            &[Part::One],
+            1,
+            Limits::default(),
+        );
+        assert_eq!(*results[0].answer.as_ref().unwrap(), Answer::from(13));
+        std::fs::remove_file(&path).unwrap();
+
+        let results = run_days(
+            Path::new("/nonexistent"),
+            &source,
+            2022,
+            &[9],
+            &[Part::One],
+            1,
+            Limits::default(),
+        );
+        let err = results[0].answer.as_ref().unwrap_err().to_string();
+        assert!(err.contains(&path.display().to_string()));
+        assert!(results[0].failed());
+    }
+
+    #[test]
+    fn test_run_days_skips_missing_input() {
+        let results = run_days(
+            Path::new("/nonexistent"),
+            &InputSource::Puzzle,
+            2022,
+            &[9],
+            &[Part::One],
+            1,
+            Limits::default(),
+        );
+        assert!(results[0].skipped());
+        assert!(!results[0].failed());
+        assert_eq!(results[0].outcome(), "skipped");
+    }
+
+    #[test]
+    fn test_run_days_catches_panics() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "100\n\n200\n").unwrap();
+        let source = InputSource::File(file.path().to_path_buf());
+        let results = run_days(
+            Path::new("/nonexistent"),
+            &source,
+            1,
+            &[1],
+            &[Part::One, Part::Two],
+            2,
+            // Limited parts are solved by the runner's executable, which a
+            // test can't register solvers with
+            Limits::default(),
+        );
+
+        assert_eq!(*results[0].answer.as_ref().unwrap(), Answer::from(3));
+        let err = results[1].answer.as_ref().unwrap_err();
+        let PuzzleError::SolverPanicked {
+            day,
+            part,
+            message,
+            location,
+        } = err.as_ref()
+        else {
+            panic!("Expected a panic, found {err}");
+        };
+        assert_eq!((*day, *part), (1, Some(Part::Two)));
+        assert_eq!(message, "Part two always panics");
+        assert!(location.as_ref().unwrap().contains("runner.rs"));
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_puzzles::year_2022::day_7::TerminalParser;
+use aoc_puzzles::{InputLine, PuzzleError};
+
+/// A day 7 terminal session that explores a real directory.
+#[derive(Debug, Default)]
+pub struct Transcript {
+    pub lines: Vec<String>,
+    /// The size of every directory on disk, by its path in the session
+    pub sizes: BTreeMap<String, usize>,
+    /// Entries left out of the session, such as symlinks and names that
+    /// can't be written in it
+    pub skipped: Vec<PathBuf>,
+}
+
+/// A directory whose size from the session doesn't match the one on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub path: String,
+    pub on_disk: Option<usize>,
+    pub parsed: Option<usize>,
+}
+
+/// Whether `name` can be written in a session and read back unchanged.
+fn is_writable(name: &str) -> bool {
+    !name.is_empty() && !name.contains(['/', '\n', '\r'])
+}
+
+/// Records a session that starts at `root` and lists every directory
+/// beneath it, entering each subdirectory in name order and leaving it again.
+pub fn record(root: &Path) -> io::Result<Transcript> {
+    let mut transcript = Transcript::default();
+    transcript.lines.push("$ cd /".to_string());
+    let root_size = record_directory(root, "/", &mut transcript)?;
+    transcript.sizes.insert("/".to_string(), root_size);
+    Ok(transcript)
+}
+
+/// Lists `dir` and everything beneath it, returning the total size of its files.
+fn record_directory(dir: &Path, path: &str, transcript: &mut Transcript) -> io::Result<usize> {
+    let mut directories = BTreeMap::new();
+    let mut files = BTreeMap::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let name = entry
+            .file_name()
+            .into_string()
+            .ok()
+            .filter(|name| is_writable(name));
+        match name {
+            Some(name) if file_type.is_dir() => {
+                directories.insert(name, entry.path());
+            }
+            Some(name) if file_type.is_file() => {
+                files.insert(name, entry.metadata()?.len() as usize);
+            }
+            _ => transcript.skipped.push(entry.path()),
+        }
+    }
+
+    transcript.lines.push("$ ls".to_string());
+    for name in directories.keys() {
+        transcript.lines.push(format!("dir {name}"));
+    }
+    for (name, size) in &files {
+        transcript.lines.push(format!("{size} {name}"));
+    }
+
+    let mut total: usize = files.values().sum();
+    for (name, child) in directories {
+        let child_path = format!("{}/{name}", path.trim_end_matches('/'));
+        transcript.lines.push(format!("$ cd {name}"));
+        let size = match record_directory(&child, &child_path, transcript) {
+            Ok(size) => size,
+            // Unreadable directories are left empty, as `ls` would fail on them
+            Err(err) if err.kind() == io::ErrorKind::PermissionDenied => {
+                transcript.lines.push("$ ls".to_string());
+                transcript.skipped.push(child);
+                0
+            }
+            Err(err) => return Err(err),
+        };
+        transcript.lines.push("$ cd ..".to_string());
+        transcript.sizes.insert(child_path, size);
+        total += size;
+    }
+    Ok(total)
+}
+
+/// Replays the session through the day 7 parser and compares the size of
+/// every directory it finds with the size on disk.
+pub fn check(transcript: &Transcript) -> Result<Vec<Mismatch>, PuzzleError> {
+    let mut parser = TerminalParser::new();
+    for (index, text) in transcript.lines.iter().enumerate() {
+        parser.parse(&InputLine::new(7, index, text))?;
+    }
+    let parsed: BTreeMap<String, usize> = parser.filesystem().directory_sizes().collect();
+
+    let mut paths: Vec<&String> = transcript.sizes.keys().chain(parsed.keys()).collect();
+    paths.sort();
+    paths.dedup();
+    Ok(paths
+        .into_iter()
+        .filter_map(|path| {
+            let on_disk = transcript.sizes.get(path).copied();
+            let parsed = parsed.get(path).copied();
+            (on_disk != parsed).then(|| Mismatch {
+                path: path.clone(),
+                on_disk,
+                parsed,
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A directory holding the puzzle's example filesystem, with the file
+    /// sizes scaled down to keep the test small.
+    fn example_dir() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        for (path, size) in [
+            ("a/e/i", 584),
+            ("a/f", 29116),
+            ("a/g", 2557),
+            ("a/h.lst", 62596),
+            ("b.txt", 14848),
+            ("c.dat", 8504),
+            ("d/j", 4060),
+            ("d/d.log", 8033),
+            ("d/d.ext", 5626),
+            ("d/k", 7214),
+        ] {
+            let path = root.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, vec![0; size]).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn test_record_and_check() {
+        let root = example_dir();
+        let transcript = record(root.path()).unwrap();
+
+        assert_eq!(
+            transcript.lines[..9],
+            [
+                "$ cd /",
+                "$ ls",
+                "dir a",
+                "dir d",
+                "14848 b.txt",
+                "8504 c.dat",
+                "$ cd a",
+                "$ ls",
+                "dir e"
+            ]
+        );
+        assert_eq!(transcript.lines.last().unwrap(), "$ cd ..");
+        assert_eq!(transcript.sizes["/a/e"], 584);
+        assert_eq!(transcript.sizes["/a"], 94853);
+        assert_eq!(transcript.sizes["/"], 143138);
+        assert_eq!(check(&transcript).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_check_finds_mismatches() {
+        let mut transcript = Transcript {
+            lines: ["$ cd /", "$ ls", "dir a", "10 b", "$ cd a", "$ ls", "5 c"]
+                .map(String::from)
+                .to_vec(),
+            ..Transcript::default()
+        };
+        transcript.sizes.insert("/".to_string(), 15);
+        transcript.sizes.insert("/a".to_string(), 4);
+        assert_eq!(
+            check(&transcript).unwrap(),
+            vec![Mismatch {
+                path: "/a".to_string(),
+                on_disk: Some(4),
+                parsed: Some(5),
+            }]
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use std::fs::File;
+use std::io::{self, BufWriter, IsTerminal, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use serde_json::json;
+
+use aoc_puzzles::{Frame, Granularity, Observer};
+
+/// The time between frames in a recording when no delay is given.
+pub const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(100);
+
+/// Clears the screen and moves the cursor to the top left.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// The asciicast header is rewritten with the size of the largest frame once
+/// every frame has been written, so it's padded to a fixed width up front.
+const ASCIICAST_HEADER_WIDTH: usize = 64;
+
+/// Draws frames in the terminal as they arrive. With a delay, and when stdout
+/// is a terminal, each frame replaces the last to animate the solution.
+/// Otherwise the frames are printed one after another.
+pub struct TerminalRenderer {
+    granularity: Granularity,
+    delay: Option<Duration>,
+    animate: bool,
+}
+
+impl TerminalRenderer {
+    pub fn new(granularity: Granularity, delay: Option<Duration>) -> Self {
+        Self {
+            granularity,
+            delay,
+            animate: delay.is_some() && io::stdout().is_terminal(),
+        }
+    }
+}
+
+impl Observer for TerminalRenderer {
+    fn granularity(&self) -> Granularity {
+        self.granularity
+    }
+
+    fn frame(&mut self, frame: Frame) {
+        if self.animate {
+            print!("{CLEAR_SCREEN}");
+        }
+        println!("{}\n", frame.render());
+        if let Some(delay) = self.delay {
+            thread::sleep(delay);
+        }
+    }
+}
+
+/// The formats frames can be saved in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingFormat {
+    /// An asciicast v2 recording, which `asciinema play` can replay
+    Asciicast,
+    /// One JSON object per frame, per line
+    JsonLines,
+}
+
+impl RecordingFormat {
+    /// Asciicast for `.cast` files, otherwise JSON lines.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("cast") => Self::Asciicast,
+            _ => Self::JsonLines,
+        }
+    }
+}
+
+/// Saves frames to a file to replay later.
+///
+/// Observers can't fail, so the first error writing a frame is kept and
+/// returned by `finish`, and later frames are dropped.
+pub struct FrameWriter<W: Write + Seek> {
+    granularity: Granularity,
+    format: RecordingFormat,
+    writer: W,
+    delay: Duration,
+    frames: usize,
+    width: usize,
+    height: usize,
+    error: Option<io::Error>,
+}
+
+impl FrameWriter<BufWriter<File>> {
+    pub fn create(path: &Path, granularity: Granularity, delay: Duration) -> io::Result<Self> {
+        let file = File::create(path)
+            .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())))?;
+        Self::new(
+            BufWriter::new(file),
+            RecordingFormat::from_path(path),
+            granularity,
+            delay,
+        )
+    }
+}
+
+impl<W: Write + Seek> FrameWriter<W> {
+    pub fn new(
+        writer: W,
+        format: RecordingFormat,
+        granularity: Granularity,
+        delay: Duration,
+    ) -> io::Result<Self> {
+        let mut frame_writer = Self {
+            granularity,
+            format,
+            writer,
+            delay,
+            frames: 0,
+            width: 0,
+            height: 0,
+            error: None,
+        };
+        if format == RecordingFormat::Asciicast {
+            frame_writer.write_asciicast_header()?;
+        }
+        Ok(frame_writer)
+    }
+
+    fn write_asciicast_header(&mut self) -> io::Result<()> {
+        let header = json!({
+            "version": 2,
+            "width": self.width.max(1),
+            "height": self.height.max(1),
+        })
+        .to_string();
+        writeln!(self.writer, "{header:<ASCIICAST_HEADER_WIDTH$}")
+    }
+
+    fn write_frame(&mut self, frame: &Frame) -> io::Result<()> {
+        match self.format {
+            RecordingFormat::Asciicast => {
+                let rendered = frame.render();
+                self.width = self.width.max(
+                    rendered
+                        .lines()
+                        .map(|line| line.chars().count())
+                        .max()
+                        .unwrap_or(0),
+                );
+                self.height = self.height.max(rendered.lines().count() + 1);
+                // Whole milliseconds keep float error out of the timestamps
+                let time = (self.delay.as_millis() * self.frames as u128) as f64 / 1000.0;
+                let output = format!("{CLEAR_SCREEN}{}\r\n", rendered.replace('\n', "\r\n"));
+                writeln!(self.writer, "{}", json!([time, "o", output]))
+            }
+            RecordingFormat::JsonLines => {
+                let mut line = serde_json::to_value(frame)?;
+                line["index"] = self.frames.into();
+                writeln!(self.writer, "{line}")
+            }
+        }
+    }
+
+    /// Flushes the recording, returning the first error from writing it.
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+        if self.format == RecordingFormat::Asciicast {
+            self.writer.seek(SeekFrom::Start(0))?;
+            self.write_asciicast_header()?;
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write + Seek> Observer for FrameWriter<W> {
+    fn granularity(&self) -> Granularity {
+        self.granularity
+    }
+
+    fn frame(&mut self, frame: Frame) {
+        if self.error.is_some() {
+            return;
+        }
+        match self.write_frame(&frame) {
+            Ok(()) => self.frames += 1,
+            Err(err) => self.error = Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn record(format: RecordingFormat) -> String {
+        let mut writer = FrameWriter::new(
+            Cursor::new(vec![]),
+            format,
+            Granularity::Instruction,
+            Duration::from_millis(500),
+        )
+        .unwrap();
+        writer.frame(Frame::grid("Initial State", vec!["H".to_string()]));
+        writer.frame(Frame::grid("R 4", vec!["s##TH".to_string()]));
+        String::from_utf8(writer.finish().unwrap().into_inner()).unwrap()
+    }
+
+    #[test]
+    fn test_record_json_lines() {
+        let lines: Vec<serde_json::Value> = record(RecordingFormat::JsonLines)
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines[1],
+            json!({"index": 1, "type": "grid", "title": "R 4", "rows": ["s##TH"]})
+        );
+    }
+
+    #[test]
+    fn test_record_asciicast() {
+        let recording = record(RecordingFormat::Asciicast);
+        let lines: Vec<serde_json::Value> = recording
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        // The longest line is the title of the first frame
+        assert_eq!(lines[0], json!({"version": 2, "width": 19, "height": 4}));
+        assert_eq!(
+            lines[2],
+            json!([0.5, "o", "\x1b[2J\x1b[H== R 4 ==\r\n\r\ns##TH\r\n"])
+        );
+    }
+
+    #[test]
+    fn test_recording_format_from_path() {
+        assert_eq!(
+            RecordingFormat::from_path(Path::new("rope.cast")),
+            RecordingFormat::Asciicast
+        );
+        assert_eq!(
+            RecordingFormat::from_path(Path::new("rope.jsonl")),
+            RecordingFormat::JsonLines
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use chrono::Local;
+
+use aoc_puzzles::data;
+
+/// The runner's crate, which holds the puzzles crate.
+const RUNNER_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// The workspace root, where the puzzles crate and runner are rebuilt on
+/// every change.
+fn workspace_dir() -> &'static Path {
+    Path::new(RUNNER_DIR)
+        .parent()
+        .expect("The runner's crate is inside the workspace")
+}
+
+/// The source file holding a day's solver.
+fn source_path(year: u16, day: u8) -> PathBuf {
+    Path::new(RUNNER_DIR)
+        .join("aoc-puzzles/src")
+        .join(format!("year_{year}"))
+        .join(format!("day_{day}.rs"))
+}
+
+/// The last modification time of every watched file. Directories are
+/// expanded to the files directly inside them, so new files are noticed too.
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, Option<SystemTime>> {
+    let mut files = BTreeMap::new();
+    for path in paths {
+        match fs::read_dir(path) {
+            Ok(entries) => {
+                for entry in entries.flatten() {
+                    files.insert(entry.path(), modified(&entry.path()));
+                }
+            }
+            Err(_) => {
+                files.insert(path.clone(), modified(path));
+            }
+        }
+    }
+    files
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Each part's answer from a run, or the message it failed with.
+type RunAnswers = BTreeMap<String, Result<String, String>>;
+
+/// Reads the runner's `--format json` output.
+fn parse_run_output(stdout: &str) -> RunAnswers {
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .map(|result| {
+            let part = result["part"].as_str().unwrap_or_default().to_string();
+            let answer = match &result["answer"] {
+                serde_json::Value::Null => Err(result["error"]["message"]
+                    .as_str()
+                    .unwrap_or("unknown error")
+                    .to_string()),
+                serde_json::Value::String(answer) => Ok(answer.clone()),
+                answer => Ok(answer.to_string()),
+            };
+            (part, answer)
+        })
+        .collect()
+}
+
+/// A line describing a part's result and how it changed since the previous run.
+fn describe_part(
+    part: &str,
+    current: &Result<String, String>,
+    previous: Option<&Result<String, String>>,
+) -> String {
+    match (current, previous) {
+        (Err(err), _) => format!("FAIL part {part}: {err}"),
+        (Ok(answer), Some(Ok(previous))) if previous == answer => {
+            format!("PASS part {part}: {answer} (unchanged)")
+        }
+        (Ok(answer), Some(Ok(previous))) => {
+            format!("PASS part {part}: {answer}\n  - previous: {previous}\n  + current:  {answer}")
+        }
+        (Ok(answer), _) => format!("PASS part {part}: {answer}"),
+    }
+}
+
+/// Rebuilds the puzzles crate, runs the day's example tests and, if they
+/// pass, solves the real input with the freshly built runner.
+fn run_once(data_root: &Path, year: u16, day: u8, previous: &RunAnswers) -> io::Result<RunAnswers> {
+    let tests = Command::new("cargo")
+        .args(["test", "--quiet", "--package", "aoc-puzzles", "--lib"])
+        .arg(format!("year_{year}::day_{day}::"))
+        .current_dir(workspace_dir())
+        .status()?;
+    if !tests.success() {
+        println!("FAIL example tests for day {day}");
+        return Ok(previous.clone());
+    }
+    println!("PASS example tests for day {day}");
+
+    let output = Command::new("cargo")
+        .args([
+            "run",
+            "--quiet",
+            "--release",
+            "--bin",
+            "advent-of-code",
+            "--",
+        ])
+        .args(["--year", &year.to_string(), "--day", &day.to_string()])
+        .arg("--data-dir")
+        .arg(data_root)
+        .args(["--format", "json"])
+        .current_dir(workspace_dir())
+        .output()?;
+    let answers = parse_run_output(&String::from_utf8_lossy(&output.stdout));
+    if answers.is_empty() {
+        print!("{}", String::from_utf8_lossy(&output.stderr));
+        println!("FAIL couldn't run day {day} against its input");
+        return Ok(previous.clone());
+    }
+    for (part, answer) in &answers {
+        println!("{}", describe_part(part, answer, previous.get(part)));
+    }
+    Ok(answers)
+}
+
+/// Re-runs a day whenever its source file or anything in its data directory
+/// changes, until interrupted.
+pub fn watch(data_root: &Path, year: u16, day: u8, interval: Duration) -> io::Result<()> {
+    let source = source_path(year, day);
+    if !source.is_file() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} doesn't exist", source.display()),
+        ));
+    }
+    let watched = [source, data::day_data_dir(data_root, year, day)];
+    for path in &watched {
+        println!("Watching {}", path.display());
+    }
+
+    let mut answers = RunAnswers::new();
+    let mut last_snapshot = snapshot(&watched);
+    loop {
+        println!("\n[{}] Running day {day}", Local::now().format("%H:%M:%S"));
+        answers = run_once(data_root, year, day, &answers)?;
+        // Wait for the files to settle so that an editor's save counts once
+        loop {
+            thread::sleep(interval);
+            let current = snapshot(&watched);
+            if current != last_snapshot {
+                last_snapshot = current;
+                break;
+            }
+        }
+        loop {
+            thread::sleep(interval);
+            let current = snapshot(&watched);
+            if current == last_snapshot {
+                break;
+            }
+            last_snapshot = current;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths() {
+        let manifest = fs::read_to_string(workspace_dir().join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("[workspace]"));
+        assert!(source_path(2022, 9).is_file());
+    }
+
+    #[test]
+    fn test_parse_run_output() {
+        let stdout = concat!(
+            r#"{"answer":13,"day":9,"error":null,"part":"One","status":"ok","year":2022}"#,
+            "\n",
+            r#"{"answer":null,"day":9,"error":{"kind":"no_solution","message":"No solution was found"},"part":"Two","status":"error","year":2022}"#,
+            "\n",
+        );
+        let answers = parse_run_output(stdout);
+        assert_eq!(answers["One"], Ok("13".to_string()));
+        assert_eq!(answers["Two"], Err("No solution was found".to_string()));
+    }
+
+    #[test]
+    fn test_describe_part() {
+        let answer = Ok("36".to_string());
+        assert_eq!(describe_part("Two", &answer, None), "PASS part Two: 36");
+        assert_eq!(
+            describe_part("Two", &answer, Some(&answer)),
+            "PASS part Two: 36 (unchanged)"
+        );
+        assert_eq!(
+            describe_part("Two", &answer, Some(&Ok("8".to_string()))),
+            "PASS part Two: 36\n  - previous: 8\n  + current:  36"
+        );
+        assert_eq!(
+            describe_part(
+                "Two",
+                &Err("No solution was found".to_string()),
+                Some(&answer)
+            ),
+            "FAIL part Two: No solution was found"
+        );
+    }
+
+    #[test]
+    fn test_snapshot_notices_new_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let watched = [dir.path().to_path_buf()];
+        let before = snapshot(&watched);
+        fs::write(dir.path().join("example.txt"), "R 4\n").unwrap();
+        assert_ne!(snapshot(&watched), before);
+    }
+}
@@ -0,0 +1,75 @@
+//! Runs the runner with `--timeout` and `--memory-limit`, which solve each
+//! part in a job process of the runner's own executable.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+/// Solves both parts of 2022 day 9 from `input` with the given limit flags,
+/// returning the JSON result for each part.
+fn run_day_9(input: &str, limit_args: &[&str]) -> Vec<Value> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_advent-of-code"))
+        .args([
+            "--year", "2022", "--day", "9", "--input", "-", "--format", "json",
+        ])
+        .args(limit_args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_limited_parts_are_solved() {
+    let results = run_day_9(
+        "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n",
+        &["--timeout", "30", "--memory-limit", "64"],
+    );
+    assert_eq!(results[0]["answer"], 13);
+    assert_eq!(results[1]["answer"], 1);
+}
+
+#[test]
+fn test_memory_limit_stops_part() {
+    let results = run_day_9("R 3000000\n", &["--memory-limit", "8"]);
+    for result in &results {
+        assert_eq!(result["error"]["kind"], "memory_limit_exceeded");
+    }
+}
+
+#[test]
+fn test_timeout_stops_part() {
+    let input = "R 1000000\nL 1000000\n".repeat(10000);
+    let start = Instant::now();
+    let results = run_day_9(&input, &["--timeout", "0.2"]);
+    assert!(start.elapsed() < Duration::from_secs(20));
+    for result in &results {
+        assert_eq!(result["error"]["kind"], "timed_out");
+    }
+}
+
+#[test]
+fn test_parse_errors_pass_between_processes() {
+    let results = run_day_9("R x\n", &["--timeout", "30"]);
+    for result in &results {
+        assert_eq!(result["error"]["kind"], "parse");
+        assert_eq!(
+            result["error"]["message"],
+            "Failed to parse day 9 input at line 1, column 3. Expected a step count, found \"x\""
+        );
+    }
+}
@@ -1,32 +1,161 @@
-use anyhow::Error;
-use futures_util::StreamExt;
-use reqwest::Client;
-
-const AOC_BASE_URL: &str = "https://adventofcode.com/2022/day";
-
-async fn fetch_data(day: u8) -> Result<(), Error> {
-    let client = Client::new();
-    let mut response = client
-        .get(format!("{AOC_BASE_URL}/{day}/input"))
-        .send()
-        .await?
-        .bytes_stream();
-    while let Some(item) = response.next().await {
-        println!("Chunk: {:?}", item?);
-    }
-    Ok(())
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Error};
+use reqwest::header::{COOKIE, USER_AGENT};
+use reqwest::{Client, Method, RequestBuilder};
+
+pub const AOC_BASE_URL: &str = "https://adventofcode.com";
+const INPUT_FILE_NAME: &str = "input.txt";
+
+/// Identifies this tool to adventofcode.com, as its automation guidelines ask.
+const AOC_USER_AGENT: &str = concat!(
+    "aoc-codegen/",
+    env!("CARGO_PKG_VERSION"),
+    " (github.com/theadd336/advent-of-code-2021)"
+);
+
+/// Authenticated access to adventofcode.com, or any server mimicking it.
+pub struct AocClient {
+    client: Client,
+    base_url: String,
+    session: String,
 }
 
-pub async fn write_input_file(day: u8) -> Result<(), Error> {
-    Ok(())
+impl AocClient {
+    pub fn new(base_url: &str, session: String) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    pub fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}", self.base_url)
+    }
+
+    fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        self.client
+            .request(method, url)
+            .header(COOKIE, format!("session={}", self.session))
+            .header(USER_AGENT, AOC_USER_AGENT)
+    }
+
+    async fn send(&self, url: &str, request: RequestBuilder) -> Result<String, Error> {
+        let response = request
+            .send()
+            .await
+            .with_context(|| format!("Failed to request {url}"))?
+            .error_for_status()
+            .with_context(|| format!("{url} returned an error. Is the session token valid?"))?;
+        Ok(response.text().await?)
+    }
+
+    async fn get(&self, url: &str) -> Result<String, Error> {
+        self.send(url, self.request(Method::GET, url)).await
+    }
+
+    /// Downloads the puzzle description page, which only includes part two
+    /// once part one has been solved.
+    pub async fn fetch_puzzle(&self, year: u16, day: u8) -> Result<String, Error> {
+        self.get(&self.day_url(year, day)).await
+    }
+
+    pub async fn fetch_input(&self, year: u16, day: u8) -> Result<String, Error> {
+        self.get(&format!("{}/input", self.day_url(year, day)))
+            .await
+    }
+
+    /// Submits an answer for a part (`level` 1 or 2), returning the response page.
+    pub async fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        level: u8,
+        answer: &str,
+    ) -> Result<String, Error> {
+        let url = format!("{}/answer", self.day_url(year, day));
+        let level = level.to_string();
+        let request = self
+            .request(Method::POST, &url)
+            .form(&[("level", level.as_str()), ("answer", answer)]);
+        self.send(&url, request).await
+    }
+}
+
+pub use aoc_puzzles::data::{day_data_dir, resolve_data_root};
+
+pub fn input_file_path(data_dir: &Path, year: u16, day: u8) -> PathBuf {
+    day_data_dir(data_dir, year, day).join(INPUT_FILE_NAME)
+}
+
+/// Downloads a day's input into the data directory, unless it has already
+/// been downloaded. Returns the path to the input file and whether it was
+/// downloaded by this call.
+pub async fn write_input_file(
+    client: &AocClient,
+    data_dir: &Path,
+    year: u16,
+    day: u8,
+) -> Result<(PathBuf, bool), Error> {
+    let path = input_file_path(data_dir, year, day);
+    if path.exists() {
+        return Ok((path, false));
+    }
+    let input = client.fetch_input(year, day).await?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, input).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok((path, true))
 }
 
 #[cfg(test)]
 mod tests {
+    use mockito::{Matcher, Server};
+
     use super::*;
 
     #[tokio::test]
-    async fn test_fetch_data() {
-        fetch_data(4).await.unwrap();
+    async fn test_write_input_file() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/2022/day/4/input")
+            .match_header("cookie", "session=abc123")
+            .match_header("user-agent", Matcher::Regex("^aoc-codegen/".to_string()))
+            .with_body("2-4,6-8\n")
+            .expect(1)
+            .create_async()
+            .await;
+        let client = AocClient::new(&server.url(), "abc123".to_string());
+        let temp = tempfile::tempdir().unwrap();
+        let data_dir = temp.path();
+
+        let (path, downloaded) = write_input_file(&client, data_dir, 2022, 4).await.unwrap();
+        assert!(downloaded);
+        assert_eq!(path, data_dir.join("2022").join("day_4").join("input.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "2-4,6-8\n");
+
+        // A second call must reuse the file rather than download it again
+        let (_, downloaded) = write_input_file(&client, data_dir, 2022, 4).await.unwrap();
+        assert!(!downloaded);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_write_input_file_rejected() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/2022/day/5/input")
+            .with_status(400)
+            .create_async()
+            .await;
+        let client = AocClient::new(&server.url(), "expired".to_string());
+        let temp = tempfile::tempdir().unwrap();
+        let data_dir = temp.path();
+
+        assert!(write_input_file(&client, data_dir, 2022, 5).await.is_err());
+        assert!(!input_file_path(data_dir, 2022, 5).exists());
     }
 }
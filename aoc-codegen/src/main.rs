@@ -1,25 +1,197 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Error;
 use chrono::Datelike;
 use chrono::Local;
-use clap::Parser;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+
+use aoc_puzzles::Part;
 
 mod data;
+mod puzzle;
+mod scaffold;
+mod session;
+mod submit;
+
+/// Today's day number, if today falls within the Advent of Code event.
+fn day_num_from_today() -> Option<u8> {
+    let today = Local::now();
+    if today.month() == 12 && today.day() <= 25 {
+        Some(today.day() as u8)
+    } else {
+        None
+    }
+}
 
-fn day_num_from_today() -> u8 {
+/// The current event during December, otherwise last year's.
+fn year_from_today() -> u16 {
     let today = Local::now();
-    today.day() as u8
+    let year = today.year() as u16;
+    if today.month() == 12 {
+        year
+    } else {
+        year - 1
+    }
+}
+
+#[derive(Copy, Clone, ValueEnum, Debug)]
+enum CliPart {
+    One,
+    Two,
+}
+
+impl From<CliPart> for Part {
+    fn from(part: CliPart) -> Self {
+        match part {
+            CliPart::One => Part::One,
+            CliPart::Two => Part::Two,
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve a part against the day's input and submit the answer
+    Submit {
+        #[arg(short, long, value_enum)]
+        part: CliPart,
+        /// Submit this answer instead of solving, e.g. letters read off a grid
+        #[arg(long)]
+        answer: Option<String>,
+    },
 }
 
-/// Simple program to greet a person
+/// Scaffolds a day's solver module and downloads its puzzle input
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// The day to codegen
-    #[arg(short, long, default_value_t=day_num_from_today())]
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// The day to codegen. Defaults to today during the event, and is
+    /// required the rest of the year
+    #[arg(short, long, global = true)]
+    day: Option<u8>,
+    /// The event year. Defaults to the current event, or last year's outside December
+    #[arg(short, long, global = true, default_value_t=year_from_today())]
+    year: u16,
+    /// The server to download from
+    #[arg(long, global = true, env = "AOC_BASE_URL", default_value = data::AOC_BASE_URL)]
+    base_url: String,
+    /// A file holding the session cookie, used when AOC_SESSION isn't set.
+    /// Defaults to ~/.config/aoc/session
+    #[arg(long, global = true)]
+    session_file: Option<PathBuf>,
+    /// The directory holding each year's data. Defaults to $AOC_DATA_DIR, then
+    /// data_dir in ~/.config/aoc/config.toml, then the puzzles crate's data
+    #[arg(long, global = true)]
+    data_dir: Option<PathBuf>,
+    /// The puzzles crate's source directory, where the day module is created
+    #[arg(long, default_value = scaffold::PUZZLES_SRC_DIR)]
+    src_dir: PathBuf,
+    /// Overwrite the day module if it already exists
+    #[arg(long)]
+    force: bool,
+}
+
+/// Builds a client from the session token. Without a token the downloads
+/// are skipped, since the module is still worth scaffolding without them.
+fn client(args: &Args) -> Option<data::AocClient> {
+    match session::load_session(args.session_file.clone()) {
+        Ok(session) => Some(data::AocClient::new(&args.base_url, session)),
+        Err(err) => {
+            eprintln!("Skipping downloads: {err}");
+            None
+        }
+    }
+}
+
+async fn fetch_input(
+    args: &Args,
+    data_root: &Path,
     day: u8,
+    client: &data::AocClient,
+) -> Result<(), Error> {
+    let (path, downloaded) = data::write_input_file(client, data_root, args.year, day).await?;
+    if downloaded {
+        println!("Downloaded day {day} input to {}", path.display());
+    } else {
+        println!("Day {day} input already exists at {}", path.display());
+    }
+    Ok(())
+}
+
+async fn run_submit(
+    args: &Args,
+    data_root: &Path,
+    day: u8,
+    part: Part,
+    answer: Option<String>,
+) -> Result<(), Error> {
+    let session = session::load_session(args.session_file.clone())?;
+    let client = data::AocClient::new(&args.base_url, session);
+    let answer = match answer {
+        Some(answer) => answer,
+        None => submit::solve(data_root, args.year, day, part)?,
+    };
+    println!("Submitting {answer} for day {day}, part {part:?}");
+    let outcome = submit::submit(&client, data_root, args.year, day, part, &answer).await?;
+    println!("The answer was {outcome}");
+    Ok(())
 }
 
 #[tokio::main]
-async fn main() {
+async fn main() -> Result<(), Error> {
     let args = Args::parse();
-    println!("{:?}", args);
+    // Outside the event, today's date would pick a real but unintended puzzle
+    let Some(day) = args.day.or_else(day_num_from_today) else {
+        Args::command()
+            .error(
+                ErrorKind::MissingRequiredArgument,
+                "--day is required outside of December 1st to 25th",
+            )
+            .exit();
+    };
+    let data_root = data::resolve_data_root(args.data_dir.clone())?;
+    if let Some(Command::Submit { part, answer }) = &args.command {
+        return run_submit(&args, &data_root, day, (*part).into(), answer.clone()).await;
+    }
+    // An existing module is left alone, but its puzzle files and input are
+    // still downloaded, e.g. to pick up part two's example once it's unlocked
+    let module_path = scaffold::module_path(&args.src_dir, args.year, day);
+    let scaffold = args.force || !module_path.exists();
+    if !scaffold {
+        println!(
+            "{} already exists, so it won't be scaffolded. Pass --force to overwrite it",
+            module_path.display()
+        );
+    }
+    let client = client(&args);
+
+    let example = match &client {
+        Some(client) => {
+            let example = puzzle::write_puzzle_files(client, &data_root, args.year, day).await?;
+            if example.input.is_none() {
+                eprintln!("No example input was found in the puzzle description");
+            }
+            example
+        }
+        None => puzzle::Example::default(),
+    };
+    if scaffold {
+        let module_path = scaffold::scaffold_day(
+            &args.src_dir,
+            &data_root,
+            args.year,
+            day,
+            &example,
+            args.force,
+        )?;
+        println!("Created {}", module_path.display());
+    }
+
+    if let Some(client) = &client {
+        fetch_input(&args, &data_root, day, client).await?;
+    }
+    Ok(())
 }
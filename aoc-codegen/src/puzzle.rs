@@ -0,0 +1,174 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Error;
+use scraper::{Html, Selector};
+
+use crate::data::{self, AocClient};
+
+const PUZZLE_FILE_NAME: &str = "puzzle.md";
+const EXAMPLE_FILE_NAME: &str = "example.txt";
+const EXAMPLE_ANSWERS_FILE_NAME: &str = "example_answers.toml";
+
+/// The first example from a puzzle description and the answers it gives.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: Option<String>,
+    pub answer_one: Option<String>,
+    pub answer_two: Option<String>,
+}
+
+fn selector(selectors: &str) -> Selector {
+    Selector::parse(selectors).expect("Selectors are valid CSS")
+}
+
+/// The description of each part that has been unlocked so far.
+fn articles(page: &Html) -> Vec<scraper::ElementRef<'_>> {
+    page.select(&selector("article.day-desc")).collect()
+}
+
+/// Converts the puzzle descriptions on the page to Markdown.
+pub fn to_markdown(html: &str) -> String {
+    let page = Html::parse_document(html);
+    articles(&page)
+        .iter()
+        .map(|article| html2md::parse_html(&article.html()).trim().to_string())
+        .collect::<Vec<String>>()
+        .join("\n\n")
+        + "\n"
+}
+
+/// Finds the first `<pre><code>` block in part one, and the highlighted
+/// answer for each part. The answer is the last highlighted `<code>` in a
+/// part's description, which is where the puzzles state the example's result.
+pub fn extract_example(html: &str) -> Example {
+    let page = Html::parse_document(html);
+    let articles = articles(&page);
+    let answer = |index: usize| {
+        let article = articles.get(index)?;
+        let highlighted = article.select(&selector("code em, em code")).last()?;
+        Some(highlighted.text().collect::<String>().trim().to_string())
+    };
+    let input = articles.first().and_then(|article| {
+        let block = article.select(&selector("pre code")).next()?;
+        Some(block.text().collect::<String>())
+    });
+    Example {
+        input,
+        answer_one: answer(0),
+        answer_two: answer(1),
+    }
+}
+
+fn example_answers_toml(example: &Example) -> String {
+    let mut answers = toml::Table::new();
+    for (part, answer) in [("one", &example.answer_one), ("two", &example.answer_two)] {
+        let Some(answer) = answer else {
+            continue;
+        };
+        let value = match answer.parse::<i64>() {
+            Ok(number) => toml::Value::Integer(number),
+            Err(_) => toml::Value::String(answer.clone()),
+        };
+        answers.insert(part.to_string(), value);
+    }
+    answers.to_string()
+}
+
+/// Downloads a day's puzzle description and saves it as Markdown, along with
+/// its first example and the example's answers, in the day's data directory.
+pub async fn write_puzzle_files(
+    client: &AocClient,
+    data_dir: &Path,
+    year: u16,
+    day: u8,
+) -> Result<Example, Error> {
+    let html = client.fetch_puzzle(year, day).await?;
+    let day_dir = data::day_data_dir(data_dir, year, day);
+    fs::create_dir_all(&day_dir)?;
+    fs::write(day_dir.join(PUZZLE_FILE_NAME), to_markdown(&html))?;
+
+    let example = extract_example(&html);
+    if let Some(input) = &example.input {
+        fs::write(day_dir.join(EXAMPLE_FILE_NAME), input)?;
+    }
+    fs::write(
+        day_dir.join(EXAMPLE_ANSWERS_FILE_NAME),
+        example_answers_toml(&example),
+    )?;
+    Ok(example)
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Server;
+
+    use super::*;
+
+    const PUZZLE_HTML: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 5: Supply Stacks ---</h2>
+<p>For example:</p>
+<pre><code>    [D]
+[N] [C]
+ 1   2
+</code></pre>
+<p>The top crates are <code>C</code> and <code>D</code>, so the answer is <code><em>CD</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>PSNRGBTFT</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Moving <em>several</em> crates gives <code><em>MCD</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_extract_example() {
+        let example = extract_example(PUZZLE_HTML);
+        assert_eq!(example.input.as_deref(), Some("    [D]\n[N] [C]\n 1   2\n"));
+        assert_eq!(example.answer_one.as_deref(), Some("CD"));
+        assert_eq!(example.answer_two.as_deref(), Some("MCD"));
+        assert_eq!(
+            example_answers_toml(&example),
+            "one = \"CD\"\ntwo = \"MCD\"\n"
+        );
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let markdown = to_markdown(PUZZLE_HTML);
+        assert!(markdown.contains("Day 5: Supply Stacks"));
+        assert!(markdown.contains("```\n    [D]\n[N] [C]\n"));
+        assert!(markdown.contains("Part Two"));
+        assert!(!markdown.contains("Your puzzle answer"));
+    }
+
+    #[tokio::test]
+    async fn test_write_puzzle_files() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/2022/day/1")
+            .with_body(
+                r#"<article class="day-desc"><pre><code>1000
+2000
+</code></pre><p>That's <code><em>3000</em></code> Calories.</p></article>"#,
+            )
+            .create_async()
+            .await;
+        let client = AocClient::new(&server.url(), "abc123".to_string());
+        let temp = tempfile::tempdir().unwrap();
+        let data_dir = temp.path();
+
+        write_puzzle_files(&client, data_dir, 2022, 1)
+            .await
+            .unwrap();
+        let day_dir = data::day_data_dir(data_dir, 2022, 1);
+        assert_eq!(
+            fs::read_to_string(day_dir.join("example.txt")).unwrap(),
+            "1000\n2000\n"
+        );
+        assert_eq!(
+            fs::read_to_string(day_dir.join("example_answers.toml")).unwrap(),
+            "one = 3000\n"
+        );
+        assert!(day_dir.join("puzzle.md").exists());
+    }
+}
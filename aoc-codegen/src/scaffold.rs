@@ -0,0 +1,242 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Error};
+
+use crate::data;
+use crate::puzzle::Example;
+
+pub const PUZZLES_SRC_DIR: &str = "./advent-of-code/aoc-puzzles/src/";
+const LIB_FILE_NAME: &str = "lib.rs";
+const YEAR_MODULE_FILE_NAME: &str = "mod.rs";
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.template");
+
+/// The expression a generated test expects for an example answer. Unknown
+/// answers become a placeholder to fill in once part two is unlocked.
+fn answer_expression(answer: Option<&str>) -> String {
+    let Some(answer) = answer else {
+        return "Answer::from(0)".to_string();
+    };
+    // Unsuffixed literals are inferred as i32, which large answers overflow
+    match answer.parse::<i64>() {
+        Ok(number) if i32::try_from(number).is_ok() => format!("Answer::from({number})"),
+        Ok(number) => format!("Answer::from({number}i64)"),
+        Err(_) => format!("Answer::from({answer:?})"),
+    }
+}
+
+/// The example input as a raw string literal, with enough `#`s that the
+/// input can't end it early. Puzzles without an example get an empty one.
+fn example_literal(input: Option<&str>) -> String {
+    let input = input.unwrap_or_default();
+    let mut hashes = String::new();
+    while input.contains(&format!("\"{hashes}")) {
+        hashes.push('#');
+    }
+    format!("r{hashes}\"{input}\"{hashes}")
+}
+
+/// Fills in the day module template. The module registers itself with the
+/// runner through `register_solver!`, and its tests embed the example input,
+/// since the data directory may be anywhere, and expect the example answers.
+pub fn render_day_module(year: u16, day: u8, example: &Example) -> String {
+    DAY_TEMPLATE
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace(
+            "{answer_one}",
+            &answer_expression(example.answer_one.as_deref()),
+        )
+        .replace(
+            "{answer_two}",
+            &answer_expression(example.answer_two.as_deref()),
+        )
+        // Last, so that nothing in the example is taken for a placeholder
+        .replace("{example}", &example_literal(example.input.as_deref()))
+}
+
+/// Adds `pub mod <module>;` to the first block of module declarations in a
+/// parent module's source, keeping the block in the order rustfmt would put it
+/// in. A parent without any declarations yet gets a new block at its end.
+/// Returns `None` if the module was already declared.
+fn declare_module(parent_source: &str, module: &str) -> Option<String> {
+    let declaration = format!("pub mod {module};");
+    let lines: Vec<&str> = parent_source.lines().collect();
+    if lines.iter().any(|line| line.trim() == declaration) {
+        return None;
+    }
+    let is_mod_line = |line: &&str| line.starts_with("mod ") || line.starts_with("pub mod ");
+    let Some(start) = lines.iter().position(is_mod_line) else {
+        let mut updated = parent_source.trim_end().to_string();
+        if !updated.is_empty() {
+            updated.push_str("\n\n");
+        }
+        return Some(updated + &declaration + "\n");
+    };
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| !is_mod_line(line))
+            .unwrap_or(lines.len() - start);
+
+    let mut block: Vec<&str> = lines[start..end].to_vec();
+    block.push(&declaration);
+    let module_name = |line: &&str| {
+        line.trim_start_matches("pub ")
+            .trim_start_matches("mod ")
+            .trim_end_matches(';')
+            .to_string()
+    };
+    block.sort_by_key(module_name);
+
+    let mut updated: Vec<&str> = lines[..start].to_vec();
+    updated.extend(block);
+    updated.extend(&lines[end..]);
+    Some(updated.join("\n") + "\n")
+}
+
+/// Declares `module` in the parent module at `path`, which is created if it
+/// doesn't exist yet.
+fn declare_module_in(path: &Path, module: &str) -> Result<(), Error> {
+    let source = match fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => {
+            return Err(Error::new(err).context(format!("Failed to read {}", path.display())))
+        }
+    };
+    if let Some(source) = declare_module(&source, module) {
+        fs::write(path, source)?;
+    }
+    Ok(())
+}
+
+/// Where a day's solver module lives under the puzzles crate's source directory.
+pub fn module_path(src_dir: &Path, year: u16, day: u8) -> PathBuf {
+    src_dir
+        .join(format!("year_{year}"))
+        .join(format!("day_{day}.rs"))
+}
+
+/// Creates `year_YYYY/day_N.rs` from the template, declares it in the year's
+/// module (declaring the year in `lib.rs` if it's new) and creates the day's
+/// data directory. Refuses to replace an existing module unless `force` is
+/// set. Returns the path to the new module.
+pub fn scaffold_day(
+    src_dir: &Path,
+    data_dir: &Path,
+    year: u16,
+    day: u8,
+    example: &Example,
+    force: bool,
+) -> Result<PathBuf, Error> {
+    let year_module = format!("year_{year}");
+    let module = format!("day_{day}");
+    let year_dir = src_dir.join(&year_module);
+    let module_path = module_path(src_dir, year, day);
+    if module_path.exists() && !force {
+        bail!(
+            "{} already exists. Pass --force to overwrite it",
+            module_path.display()
+        );
+    }
+    let lib_path = src_dir.join(LIB_FILE_NAME);
+    if !lib_path.exists() {
+        bail!("{} doesn't exist", lib_path.display());
+    }
+
+    fs::create_dir_all(&year_dir)?;
+    fs::write(&module_path, render_day_module(year, day, example))?;
+    declare_module_in(&year_dir.join(YEAR_MODULE_FILE_NAME), &module)?;
+    declare_module_in(&lib_path, &year_module)?;
+    fs::create_dir_all(data::day_data_dir(data_dir, year, day))?;
+    Ok(module_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB_SOURCE: &str = "use std::io;
+
+mod answer;
+mod parsing;
+pub mod registry;
+pub mod year_2021;
+pub mod year_2022;
+
+pub use answer::Answer;
+";
+
+    #[test]
+    fn test_declare_module() {
+        let updated = declare_module(LIB_SOURCE, "year_2015").unwrap();
+        assert!(updated.contains("pub mod registry;\npub mod year_2015;\npub mod year_2021;"));
+        assert!(updated.ends_with("pub mod year_2022;\n\npub use answer::Answer;\n"));
+        assert_eq!(declare_module(&updated, "year_2015"), None);
+
+        let year_source = declare_module("", "day_1").unwrap();
+        assert_eq!(year_source, "pub mod day_1;\n");
+        let year_source = declare_module(&year_source, "day_10").unwrap();
+        assert_eq!(year_source, "pub mod day_1;\npub mod day_10;\n");
+    }
+
+    #[test]
+    fn test_answer_expression() {
+        assert_eq!(answer_expression(Some("21")), "Answer::from(21)");
+        assert_eq!(
+            answer_expression(Some("24933642000")),
+            "Answer::from(24933642000i64)"
+        );
+        assert_eq!(answer_expression(Some("CMZ")), "Answer::from(\"CMZ\")");
+        assert_eq!(answer_expression(None), "Answer::from(0)");
+    }
+
+    #[test]
+    fn test_example_literal() {
+        assert_eq!(example_literal(Some("1\n2\n")), "r\"1\n2\n\"");
+        assert_eq!(
+            example_literal(Some("say \"hi\"#\n")),
+            "r##\"say \"hi\"#\n\"##"
+        );
+        assert_eq!(example_literal(None), "r\"\"");
+    }
+
+    #[test]
+    fn test_scaffold_day() {
+        let root = tempfile::tempdir().unwrap();
+        let (src_dir, data_dir) = (root.path().join("src"), root.path().join("data"));
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::write(src_dir.join("lib.rs"), LIB_SOURCE).unwrap();
+
+        let example = Example {
+            input: Some("1000\n2000\n\n3000\n".to_string()),
+            answer_one: Some("24000".to_string()),
+            ..Example::default()
+        };
+        let module_path = scaffold_day(&src_dir, &data_dir, 2022, 10, &example, false).unwrap();
+        let module = fs::read_to_string(&module_path).unwrap();
+        assert!(module.contains("register_solver!(2022, 10, Solver);"));
+        assert!(module.contains("const DAY: u8 = 10;"));
+        assert!(module.contains("const EXAMPLE: &str = r\"1000\n2000\n\n3000\n\";"));
+        assert!(module.contains("assert_eq!(ans, Answer::from(24000));"));
+        assert!(module.contains("assert_eq!(ans, Answer::from(0));"));
+        assert_eq!(module_path, src_dir.join("year_2022/day_10.rs"));
+        assert_eq!(
+            fs::read_to_string(src_dir.join("year_2022/mod.rs")).unwrap(),
+            "pub mod day_10;\n"
+        );
+        assert!(data_dir.join("2022/day_10").is_dir());
+
+        fs::write(&module_path, "// solved").unwrap();
+        assert!(scaffold_day(&src_dir, &data_dir, 2022, 10, &example, false).is_err());
+        assert_eq!(fs::read_to_string(&module_path).unwrap(), "// solved");
+
+        scaffold_day(&src_dir, &data_dir, 2022, 10, &example, true).unwrap();
+        let year_source = fs::read_to_string(src_dir.join("year_2022/mod.rs")).unwrap();
+        assert_eq!(year_source.matches("pub mod day_10;").count(), 1);
+        let lib_source = fs::read_to_string(src_dir.join("lib.rs")).unwrap();
+        assert_eq!(lib_source.matches("pub mod year_2022;").count(), 1);
+    }
+}
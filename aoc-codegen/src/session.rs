@@ -0,0 +1,38 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context, Error};
+
+/// Environment variable holding the adventofcode.com session cookie.
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// The default config file holding the session cookie, used when
+/// `AOC_SESSION` isn't set: `$XDG_CONFIG_HOME/aoc/session`, falling back to
+/// `~/.config/aoc/session`.
+pub fn default_session_file() -> Option<PathBuf> {
+    Some(aoc_puzzles::data::config_dir()?.join("session"))
+}
+
+/// Reads the session token, preferring the environment over the config file.
+pub fn load_session(session_file: Option<PathBuf>) -> Result<String, Error> {
+    if let Ok(session) = env::var(SESSION_ENV_VAR) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+    let session_file = session_file.or_else(default_session_file).ok_or_else(|| {
+        anyhow!("No session token found. Set {SESSION_ENV_VAR} or pass --session-file")
+    })?;
+    let session = fs::read_to_string(&session_file).with_context(|| {
+        format!(
+            "No session token found. Set {SESSION_ENV_VAR} or save it to {}",
+            session_file.display()
+        )
+    })?;
+    let session = session.trim();
+    if session.is_empty() {
+        return Err(anyhow!("{} is empty", session_file.display()));
+    }
+    Ok(session.to_string())
+}
@@ -0,0 +1,405 @@
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Error};
+use chrono::{DateTime, Utc};
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+
+use aoc_puzzles::known_answers::KnownAnswers;
+use aoc_puzzles::{registry, Answer, Part};
+
+use crate::data::{self, AocClient};
+
+const SUBMISSIONS_FILE_NAME: &str = "submissions.toml";
+
+/// What adventofcode.com made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The part was solved before, so the answer wasn't checked
+    AlreadySolved,
+    /// The answer was submitted too soon after the last one and wasn't checked
+    RateLimited {
+        wait: Duration,
+    },
+}
+
+impl Outcome {
+    /// Whether the answer was checked, and so is worth recording.
+    fn is_verdict(&self) -> bool {
+        matches!(
+            self,
+            Self::Correct | Self::TooHigh | Self::TooLow | Self::Wrong
+        )
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "wrong, too high"),
+            Self::TooLow => write!(f, "wrong, too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::AlreadySolved => write!(f, "not checked, the part is already solved"),
+            Self::RateLimited { wait } => {
+                write!(f, "not checked, wait {}s before retrying", wait.as_secs())
+            }
+        }
+    }
+}
+
+/// Reads a wait like `1m 39s` or `one minute` out of a response message.
+fn parse_wait(message: &str) -> Option<Duration> {
+    if let Some((_, rest)) = message.split_once("You have ") {
+        let (wait, _) = rest.split_once(" left to wait")?;
+        let mut seconds = 0;
+        for piece in wait.split_whitespace() {
+            let unit = match piece.chars().last()? {
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return None,
+            };
+            seconds += piece[..piece.len() - 1].parse::<u64>().ok()? * unit;
+        }
+        return Some(Duration::from_secs(seconds));
+    }
+    let message = message.to_lowercase();
+    let (_, rest) = message.split_once("wait ")?;
+    let mut pieces = rest.split_whitespace();
+    let amount = match pieces.next()? {
+        "one" | "a" => 1,
+        amount => amount.parse().ok()?,
+    };
+    let unit = match pieces.next()? {
+        unit if unit.starts_with("second") => 1,
+        unit if unit.starts_with("minute") => 60,
+        unit if unit.starts_with("hour") => 3600,
+        _ => return None,
+    };
+    Some(Duration::from_secs(amount * unit))
+}
+
+/// Parses the page returned for a submission into its outcome and how long
+/// to wait before submitting again, if the server asked for a wait.
+pub fn parse_response(html: &str) -> Result<(Outcome, Option<Duration>), Error> {
+    let page = Html::parse_document(html);
+    let selector = Selector::parse("article").expect("Selectors are valid CSS");
+    let message: String = page
+        .select(&selector)
+        .next()
+        .map(|article| article.text().collect())
+        .unwrap_or_default();
+    let message = message.split_whitespace().collect::<Vec<&str>>().join(" ");
+    let wait = parse_wait(&message);
+
+    let outcome = if message.contains("That's the right answer") {
+        Outcome::Correct
+    } else if message.contains("You gave an answer too recently") {
+        Outcome::RateLimited {
+            wait: wait.unwrap_or_default(),
+        }
+    } else if message.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            Outcome::TooHigh
+        } else if message.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else {
+        bail!("Couldn't understand the response to the submission: {message:?}");
+    };
+    Ok((outcome, wait))
+}
+
+/// A single answer that was checked by the server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub level: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// Every answer submitted for a day, stored next to its input so the same
+/// wrong answer is never sent twice.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmissionLog {
+    /// Submitting again before this time would only be rate limited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<DateTime<Utc>>,
+    #[serde(default, rename = "submission")]
+    pub submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn path(data_dir: &Path, year: u16, day: u8) -> PathBuf {
+        data::day_data_dir(data_dir, year, day).join(SUBMISSIONS_FILE_NAME)
+    }
+
+    /// Loads a day's log. A missing file means nothing has been submitted yet.
+    pub fn load(data_dir: &Path, year: u16, day: u8) -> Result<Self, Error> {
+        match fs::read_to_string(Self::path(data_dir, year, day)) {
+            Ok(contents) => Ok(toml::from_str(&contents)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn save(&self, data_dir: &Path, year: u16, day: u8) -> Result<(), Error> {
+        let path = Self::path(data_dir, year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Explains why an answer shouldn't be submitted, based on earlier outcomes.
+    pub fn refusal(&self, level: u8, answer: &str, now: DateTime<Utc>) -> Option<String> {
+        let mut submissions = self.submissions.iter().filter(|s| s.level == level);
+        if let Some(correct) = submissions
+            .clone()
+            .find(|submission| submission.outcome == Outcome::Correct)
+        {
+            return Some(format!(
+                "Part {level} was already solved with {}",
+                correct.answer
+            ));
+        }
+        if let Some(previous) = submissions.clone().find(|s| s.answer == answer) {
+            return Some(format!(
+                "{answer} was already submitted on {} and was {}",
+                previous.submitted_at, previous.outcome
+            ));
+        }
+        // Known bounds rule out answers that were never submitted themselves
+        if let Ok(number) = answer.parse::<i64>() {
+            let out_of_bounds = submissions.find(|submission| {
+                match (submission.outcome, submission.answer.parse::<i64>()) {
+                    (Outcome::TooHigh, Ok(bound)) => number >= bound,
+                    (Outcome::TooLow, Ok(bound)) => number <= bound,
+                    _ => false,
+                }
+            });
+            if let Some(bound) = out_of_bounds {
+                return Some(format!(
+                    "{answer} can't be right, since {} was {}",
+                    bound.answer, bound.outcome
+                ));
+            }
+        }
+        match self.retry_after {
+            Some(retry_after) if retry_after > now => Some(format!(
+                "Submitting again is rate limited for another {}s",
+                (retry_after - now).num_seconds().max(1)
+            )),
+            _ => None,
+        }
+    }
+
+    /// Records the result of a submission, including any wait it imposed.
+    pub fn record(
+        &mut self,
+        level: u8,
+        answer: &str,
+        outcome: Outcome,
+        wait: Option<Duration>,
+        now: DateTime<Utc>,
+    ) {
+        if outcome.is_verdict() {
+            self.submissions.push(Submission {
+                level,
+                answer: answer.to_string(),
+                outcome,
+                submitted_at: now,
+            });
+        }
+        self.retry_after = wait
+            .and_then(|wait| chrono::Duration::from_std(wait).ok())
+            .map(|wait| now + wait);
+    }
+}
+
+fn level(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+/// Solves a part against the day's input file, producing the text to submit.
+pub fn solve(data_dir: &Path, year: u16, day: u8, part: Part) -> Result<String, Error> {
+    let path = data::input_file_path(data_dir, year, day);
+    let input = File::open(&path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let lines = BufReader::new(input)
+        .lines()
+        .collect::<Result<Vec<String>, io::Error>>()?;
+    let answer = registry::get_solver(year, day)?.solve(Box::new(lines.into_iter()), part)?;
+    match answer {
+        Answer::Grid(_) => Err(anyhow!(
+            "Day {day}, part {part:?} answered with a grid. Read it and submit it by hand:\n{answer}"
+        )),
+        answer => Ok(answer.to_string()),
+    }
+}
+
+/// Saves a correct answer to the day's `answers.toml`, for `--verify` runs.
+fn record_correct_answer(
+    data_dir: &Path,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<(), Error> {
+    let mut known_answers = KnownAnswers::load(data_dir, year, day)?;
+    let answer: Answer = answer.parse()?;
+    known_answers.set(part, &answer);
+    known_answers.save(data_dir, year, day)?;
+    Ok(())
+}
+
+/// Submits an answer unless earlier submissions already rule it out, and
+/// records the outcome.
+pub async fn submit(
+    client: &AocClient,
+    data_dir: &Path,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Outcome, Error> {
+    let level = level(part);
+    let mut log = SubmissionLog::load(data_dir, year, day)?;
+    if let Some(refusal) = log.refusal(level, answer, Utc::now()) {
+        bail!("Not submitting day {day}, part {part:?}: {refusal}");
+    }
+
+    let html = client.submit_answer(year, day, level, answer).await?;
+    let (outcome, wait) = parse_response(&html)?;
+    log.record(level, answer, outcome, wait, Utc::now());
+    log.save(data_dir, year, day)?;
+    if outcome == Outcome::Correct {
+        record_correct_answer(data_dir, year, day, part, answer)?;
+    }
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::{Matcher, Server};
+
+    use super::*;
+
+    fn response(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let parse = |message: &str| parse_response(&response(message)).unwrap();
+        assert_eq!(
+            parse("That's the right answer! You are one gold star closer."),
+            (Outcome::Correct, None)
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too high. Please wait one minute before trying again."),
+            (Outcome::TooHigh, Some(Duration::from_secs(60)))
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too low. Please wait 5 minutes before trying again."),
+            (Outcome::TooLow, Some(Duration::from_secs(300)))
+        );
+        assert_eq!(
+            parse("That's not the right answer. If you're stuck, make sure you're using the full input data."),
+            (Outcome::Wrong, None)
+        );
+        assert_eq!(
+            parse("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 39s left to wait."),
+            (
+                Outcome::RateLimited {
+                    wait: Duration::from_secs(99)
+                },
+                Some(Duration::from_secs(99))
+            )
+        );
+        assert_eq!(
+            parse("You don't seem to be solving the right level. Did you already complete it?"),
+            (Outcome::AlreadySolved, None)
+        );
+        assert!(parse_response(&response("Something else")).is_err());
+    }
+
+    #[test]
+    fn test_submission_log_refusal() {
+        let now = Utc::now();
+        let mut log = SubmissionLog::default();
+        log.record(1, "500", Outcome::TooHigh, None, now);
+        log.record(1, "100", Outcome::TooLow, None, now);
+        assert!(log.refusal(1, "500", now).is_some());
+        assert!(log.refusal(1, "600", now).is_some());
+        assert!(log.refusal(1, "50", now).is_some());
+        assert_eq!(log.refusal(1, "300", now), None);
+        assert_eq!(log.refusal(2, "500", now), None);
+
+        log.record(
+            1,
+            "300",
+            Outcome::Correct,
+            Some(Duration::from_secs(60)),
+            now,
+        );
+        assert!(log.refusal(1, "301", now).is_some());
+        assert!(log.refusal(2, "1", now).is_some());
+        assert_eq!(
+            log.refusal(2, "1", now + chrono::Duration::minutes(2)),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_submit() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/2022/day/1/answer")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("level".to_string(), "1".to_string()),
+                Matcher::UrlEncoded("answer".to_string(), "70698".to_string()),
+            ]))
+            .with_body(response(
+                "That's not the right answer; your answer is too low.",
+            ))
+            .expect(1)
+            .create_async()
+            .await;
+        let client = AocClient::new(&server.url(), "abc123".to_string());
+        let temp = tempfile::tempdir().unwrap();
+        let data_dir = temp.path();
+
+        let outcome = submit(&client, data_dir, 2022, 1, Part::One, "70698")
+            .await
+            .unwrap();
+        assert_eq!(outcome, Outcome::TooLow);
+        // The same wrong answer must never reach the server again
+        assert!(submit(&client, data_dir, 2022, 1, Part::One, "70698")
+            .await
+            .is_err());
+        mock.assert_async().await;
+
+        let log = SubmissionLog::load(data_dir, 2022, 1).unwrap();
+        assert_eq!(log.submissions.len(), 1);
+        assert_eq!(log.submissions[0].outcome, Outcome::TooLow);
+    }
+}